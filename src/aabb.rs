@@ -24,6 +24,21 @@ impl Aabb {
         tmax > tmin
     }
 
+    /// Part of [t_min, t_max] that the ray spends inside the box, None if it misses
+    pub fn clip(&self, ray: &Ray, t_min: f64, t_max: f64) -> Option<(f64, f64)> {
+        let min = (self.min - ray.origin) / ray.direction;
+        let max = (self.max - ray.origin) / ray.direction;
+
+        let start = Vec3::min(min, max).max_element(t_min);
+        let end = Vec3::max(min, max).min_element(t_max);
+
+        if end > start {
+            Some((start, end))
+        } else {
+            None
+        }
+    }
+
     pub fn surrounding_box(box0: Aabb, box1: Aabb) -> Self {
         let smol_box = Vec3::min(box0.min, box1.min);
         let big_box = Vec3::max(box0.max, box1.max);
@@ -0,0 +1,21 @@
+use crate::{
+    types::{Ray, Vec3},
+    Background,
+};
+
+/// Same color in every direction
+pub struct Constant {
+    color: Vec3,
+}
+
+impl Constant {
+    pub fn new(color: Vec3) -> Self {
+        Self { color }
+    }
+}
+
+impl Background for Constant {
+    fn value(&self, _ray: &Ray) -> Vec3 {
+        self.color
+    }
+}
@@ -0,0 +1,113 @@
+// Piecewise constant distributions used to importance sample images
+// See, https://pbr-book.org/3ed-2018/Monte_Carlo_Integration/2D_Sampling_with_Multidimensional_Transformations
+
+pub struct Distribution1D {
+    func: Vec<f64>,
+    cdf: Vec<f64>,
+    func_integral: f64,
+}
+
+impl Distribution1D {
+    pub fn new(func: Vec<f64>) -> Self {
+        let n = func.len();
+        let mut cdf = vec![0.0; n + 1];
+
+        for i in 1..=n {
+            cdf[i] = cdf[i - 1] + func[i - 1] / n as f64;
+        }
+
+        let func_integral = cdf[n];
+
+        if func_integral == 0.0 {
+            // Nothing to go on, Fallback to uniform sampling
+            for (i, c) in cdf.iter_mut().enumerate() {
+                *c = i as f64 / n as f64;
+            }
+        } else {
+            for c in cdf.iter_mut() {
+                *c /= func_integral;
+            }
+        }
+
+        Self {
+            func,
+            cdf,
+            func_integral,
+        }
+    }
+
+    /// Maps a uniform sample in [0, 1) to a point in [0, 1) and the pdf of picking it
+    pub fn sample_continuous(&self, sample: f64) -> (f64, f64) {
+        // Index of the last cdf entry that's <= sample
+        let offset = self
+            .cdf
+            .partition_point(|&c| c <= sample)
+            .saturating_sub(1)
+            .min(self.func.len() - 1);
+
+        let mut du = sample - self.cdf[offset];
+        let width = self.cdf[offset + 1] - self.cdf[offset];
+        if width > 0.0 {
+            du /= width;
+        }
+
+        let x = (offset as f64 + du) / self.func.len() as f64;
+
+        (x, self.pdf(x))
+    }
+
+    pub fn pdf(&self, x: f64) -> f64 {
+        if self.func_integral == 0.0 {
+            return 1.0;
+        }
+
+        let n = self.func.len();
+        let offset = ((x * n as f64) as usize).min(n - 1);
+
+        self.func[offset] / self.func_integral
+    }
+
+    pub fn integral(&self) -> f64 {
+        self.func_integral
+    }
+}
+
+/// Distribution over [0, 1)^2 from a grid of weights, Stored row by row
+pub struct Distribution2D {
+    // Distribution over u for each row
+    conditional: Vec<Distribution1D>,
+    // Distribution over the rows(v)
+    marginal: Distribution1D,
+}
+
+impl Distribution2D {
+    pub fn new(func: &[f64], width: usize, height: usize) -> Self {
+        let conditional = func
+            .chunks(width)
+            .take(height)
+            .map(|row| Distribution1D::new(row.to_vec()))
+            .collect::<Vec<_>>();
+
+        let marginal = Distribution1D::new(conditional.iter().map(|c| c.integral()).collect());
+
+        Self {
+            conditional,
+            marginal,
+        }
+    }
+
+    /// Returns a point (u, v) in [0, 1)^2 and its pdf
+    pub fn sample_continuous(&self, sample: (f64, f64)) -> ((f64, f64), f64) {
+        let (v, pdf_v) = self.marginal.sample_continuous(sample.1);
+        let row = ((v * self.conditional.len() as f64) as usize).min(self.conditional.len() - 1);
+        let (u, pdf_u) = self.conditional[row].sample_continuous(sample.0);
+
+        ((u, v), pdf_u * pdf_v)
+    }
+
+    pub fn pdf(&self, (u, v): (f64, f64)) -> f64 {
+        let row = ((v * self.conditional.len() as f64) as usize).min(self.conditional.len() - 1);
+
+        self.conditional[row].pdf(u) * self.marginal.pdf(v)
+    }
+}
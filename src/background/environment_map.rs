@@ -0,0 +1,143 @@
+use std::f64::consts::PI;
+
+use image::error::ImageError;
+use rand::{rngs::SmallRng, Rng};
+
+use crate::{
+    background::distribution::Distribution2D,
+    texture::{Filter, ImageTexture, Wrap},
+    types::{Ray, Vec3},
+    Background, Texture,
+};
+
+/// Light coming from infinitely far away in every direction, Looked up in an
+/// equirectangular(latitude/longitude) image. Usually a HDR photo of the sky.
+///
+/// The mapping between directions & the image is the same as the texture coordinates of a `Sphere`.
+/// The center of the image is towards +X and the top row is straight up
+pub struct EnvironmentMap {
+    texture: ImageTexture,
+    intensity: f64,
+    // Rotation around the Y axis
+    sin_theta: f64,
+    cos_theta: f64,
+    // Luminance of every texel, Used to send more rays towards the bright parts of the image
+    distribution: Distribution2D,
+}
+
+impl EnvironmentMap {
+    pub fn from_filename(filename: &str) -> Result<Self, ImageError> {
+        Ok(Self::new(ImageTexture::from_filename(filename)?))
+    }
+
+    /// Uses an equirectangular texture that's already in memory, Like a baked `Sky`
+    pub fn new(texture: ImageTexture) -> Self {
+        let texture = texture
+            .with_filter(Filter::Bilinear)
+            .with_wrap(Wrap::Repeat);
+
+        let (width, height) = texture.dimensions();
+
+        let mut luminance = Vec::with_capacity(width * height);
+        for j in 0..height {
+            // Rows near the poles cover a smaller solid angle
+            let t = (j as f64 + 0.5) / height as f64;
+            let sin_theta = (PI * t).sin();
+
+            for i in 0..width {
+                let s = (i as f64 + 0.5) / width as f64;
+                let color = texture.value(s, 1.0 - t, Vec3::splat(0.0));
+
+                luminance.push(
+                    (0.2126 * color.x() + 0.7152 * color.y() + 0.0722 * color.z()) * sin_theta,
+                );
+            }
+        }
+
+        Self {
+            texture,
+            intensity: 1.0,
+            sin_theta: 0.0,
+            cos_theta: 1.0,
+            distribution: Distribution2D::new(&luminance, width, height),
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn with_intensity(mut self, intensity: f64) -> Self {
+        self.intensity = intensity;
+        self
+    }
+
+    /// Rotates the environment around the Y axis by angle degrees
+    pub fn with_rotation(mut self, angle: f64) -> Self {
+        let radians = angle.to_radians();
+        self.sin_theta = radians.sin();
+        self.cos_theta = radians.cos();
+        self
+    }
+
+    fn rotate(&self, d: &Vec3) -> Vec3 {
+        Vec3::new(
+            self.cos_theta * d.x() + self.sin_theta * d.z(),
+            d.y(),
+            -self.sin_theta * d.x() + self.cos_theta * d.z(),
+        )
+    }
+
+    fn unrotate(&self, d: &Vec3) -> Vec3 {
+        Vec3::new(
+            self.cos_theta * d.x() - self.sin_theta * d.z(),
+            d.y(),
+            self.sin_theta * d.x() + self.cos_theta * d.z(),
+        )
+    }
+}
+
+impl Background for EnvironmentMap {
+    fn value(&self, ray: &Ray) -> Vec3 {
+        let (u, v) = direction_to_uv(&self.unrotate(&ray.direction));
+
+        self.texture.value(u, v, ray.direction) * self.intensity
+    }
+
+    fn sample_direction(&self, rng: &mut SmallRng) -> Option<Vec3> {
+        let ((s, t), _) = self.distribution.sample_continuous((rng.gen(), rng.gen()));
+
+        Some(self.rotate(&uv_to_direction(s, 1.0 - t)))
+    }
+
+    /// With respect to solid angle
+    fn pdf(&self, direction: &Vec3) -> Option<f64> {
+        let (u, v) = direction_to_uv(&self.unrotate(direction));
+        let sin_theta = (PI * v).sin();
+
+        if sin_theta <= 0.0 {
+            return Some(0.0);
+        }
+
+        // The image covers 2PI x PI radians
+        Some(self.distribution.pdf((u, 1.0 - v)) / (2.0 * PI * PI * sin_theta))
+    }
+}
+
+/// Texture coordinates of direction in an equirectangular image
+pub(super) fn direction_to_uv(direction: &Vec3) -> (f64, f64) {
+    let d = direction.unit_vector();
+
+    let theta = (-d.y()).clamp(-1.0, 1.0).acos();
+    let phi = f64::atan2(-d.z(), d.x()) + PI;
+
+    (phi / (2.0 * PI), theta / PI)
+}
+
+pub(super) fn uv_to_direction(u: f64, v: f64) -> Vec3 {
+    let theta = v * PI;
+    let phi = 2.0 * PI * u - PI;
+
+    Vec3::new(
+        theta.sin() * phi.cos(),
+        -theta.cos(),
+        -theta.sin() * phi.sin(),
+    )
+}
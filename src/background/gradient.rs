@@ -0,0 +1,25 @@
+use crate::{
+    types::{Ray, Vec3},
+    Background,
+};
+
+/// Blends from bottom when looking straight down to top when looking straight up.
+/// With white & light blue this is the sky from the first book
+pub struct VerticalGradient {
+    bottom: Vec3,
+    top: Vec3,
+}
+
+impl VerticalGradient {
+    pub fn new(bottom: Vec3, top: Vec3) -> Self {
+        Self { bottom, top }
+    }
+}
+
+impl Background for VerticalGradient {
+    fn value(&self, ray: &Ray) -> Vec3 {
+        let t = 0.5 * (ray.direction.unit_vector().y() + 1.0);
+
+        self.bottom * (1.0 - t) + self.top * t
+    }
+}
@@ -0,0 +1,33 @@
+mod constant;
+mod distribution;
+mod environment_map;
+mod gradient;
+mod screen_texture;
+mod sky;
+
+pub use constant::Constant;
+pub use environment_map::EnvironmentMap;
+pub use gradient::VerticalGradient;
+pub use screen_texture::ScreenTexture;
+pub use sky::Sky;
+
+use rand::rngs::SmallRng;
+
+use crate::types::{Ray, Vec3};
+
+/// Whatever a ray sees after it escapes the scene without hitting anything
+pub trait Background: Send + Sync {
+    fn value(&self, ray: &Ray) -> Vec3;
+
+    // sample_direction picks a direction with probability roughly proportional to the light
+    // coming from it & pdf is the probability density of picking direction. They are only
+    // implemented by backgrounds that are meant to light the scene, Renderer can then send
+    // some of the scattered rays straight towards the bright parts. None for everything else
+    fn sample_direction(&self, _rng: &mut SmallRng) -> Option<Vec3> {
+        None
+    }
+
+    fn pdf(&self, _direction: &Vec3) -> Option<f64> {
+        None
+    }
+}
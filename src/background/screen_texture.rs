@@ -0,0 +1,36 @@
+use crate::{
+    types::{Ray, Vec3},
+    Background, Camera, Texture,
+};
+
+/// Texture stretched over the image like a backdrop behind the scene, (u, v) is the position
+/// on the screen where the ray would land if it were shot from the camera. Reflections &
+/// refractions see whatever part of the backdrop is in that direction, Clamped to its edges.
+/// Rays going behind the camera see the middle column of the texture
+pub struct ScreenTexture<T: Texture> {
+    texture: T,
+    camera: Camera,
+}
+
+impl<T: Texture> ScreenTexture<T> {
+    pub fn new(texture: T, camera: &Camera) -> Self {
+        Self {
+            texture,
+            camera: camera.clone(),
+        }
+    }
+}
+
+impl<T: Texture + Send + Sync> Background for ScreenTexture<T> {
+    fn value(&self, ray: &Ray) -> Vec3 {
+        let (u, v) = match self.camera.screen_position(&ray.direction) {
+            Some((u, v)) => (u.clamp(0.0, 1.0), v.clamp(0.0, 1.0)),
+            None => {
+                let d = ray.direction.unit_vector();
+                (0.5, 0.5 * (d.y() + 1.0))
+            }
+        };
+
+        self.texture.value(u, v, Vec3::new(u, v, 0.0))
+    }
+}
@@ -0,0 +1,213 @@
+use std::f64::consts::PI;
+
+use crate::{
+    background::{
+        environment_map::{direction_to_uv, uv_to_direction},
+        EnvironmentMap,
+    },
+    texture::ImageTexture,
+    types::Vec3,
+};
+
+// Converts the luminance of the model(kcd/m^2) into the units used by the renderer
+// Picked so that a white diffuse surface under the midday sun comes out close to 1.0
+const SCALE: f64 = 0.03;
+
+// Luminance of the sun just outside the atmosphere(kcd/m^2)
+const SUN_LUMINANCE: f64 = 2.0e6;
+
+// Angular radius of the sun as seen from the ground
+const SUN_RADIUS: f64 = 0.004_65;
+
+// Wavelengths(in micrometers) used for the red, green & blue channels
+const RGB_WAVELENGTHS: [f64; 3] = [0.65, 0.55, 0.45];
+
+/// Clear sky daylight from the analytic model by Preetham, Shirley & Smits,
+/// See, https://www.cs.utah.edu/~shirley/papers/sunsky/sunsky.pdf
+///
+/// turbidity is the amount of haze in the air, 2.0 is a very clear day & 10.0 is a hazy one.
+/// Everything below the horizon is ground which reflects the light of the sky & the sun.
+/// The model only holds while the sun is above the horizon
+pub struct Sky {
+    sun_direction: Vec3,
+    // Angle between the zenith & the sun
+    sun_theta: f64,
+    // Perez coefficients & zenith value for the luminance Y, and the chromaticities x & y
+    coefficients: [[f64; 5]; 3],
+    zenith: [f64; 3],
+    sun_radiance: Vec3,
+    ground: Vec3,
+}
+
+impl Sky {
+    pub fn new(sun_direction: Vec3, turbidity: f64, ground_albedo: Vec3) -> Self {
+        let sun_direction = sun_direction.unit_vector();
+        let sun_theta = sun_direction.y().clamp(0.01, 1.0).acos();
+
+        let t = turbidity;
+
+        let coefficients = [
+            [
+                0.1787 * t - 1.4630,
+                -0.3554 * t + 0.4275,
+                -0.0227 * t + 5.3251,
+                0.1206 * t - 2.5771,
+                -0.0670 * t + 0.3703,
+            ],
+            [
+                -0.0193 * t - 0.2592,
+                -0.0665 * t + 0.0008,
+                -0.0004 * t + 0.2125,
+                -0.0641 * t - 0.8989,
+                -0.0033 * t + 0.0452,
+            ],
+            [
+                -0.0167 * t - 0.2608,
+                -0.0950 * t + 0.0092,
+                -0.0079 * t + 0.2102,
+                -0.0441 * t - 1.6537,
+                -0.0109 * t + 0.0529,
+            ],
+        ];
+
+        let chi = (4.0 / 9.0 - t / 120.0) * (PI - 2.0 * sun_theta);
+        let zenith_luminance = (4.0453 * t - 4.9710) * chi.tan() - 0.2155 * t + 2.4192;
+
+        let (theta, theta2, theta3) = (sun_theta, sun_theta.powi(2), sun_theta.powi(3));
+        let zenith_x = t * t * (0.00166 * theta3 - 0.00375 * theta2 + 0.00209 * theta)
+            + t * (-0.02903 * theta3 + 0.06377 * theta2 - 0.03202 * theta + 0.00394)
+            + (0.11693 * theta3 - 0.21196 * theta2 + 0.06052 * theta + 0.25886);
+        let zenith_y = t * t * (0.00275 * theta3 - 0.00610 * theta2 + 0.00317 * theta)
+            + t * (-0.04214 * theta3 + 0.08970 * theta2 - 0.04153 * theta + 0.00516)
+            + (0.15346 * theta3 - 0.26756 * theta2 + 0.06670 * theta + 0.26688);
+
+        let mut sky = Self {
+            sun_direction,
+            sun_theta,
+            coefficients,
+            zenith: [zenith_luminance, zenith_x, zenith_y],
+            sun_radiance: sun_radiance(sun_theta, turbidity),
+            ground: Vec3::splat(0.0),
+        };
+
+        // Light falling on the ground, The sun plus the sky integrated over the hemisphere
+        let sun_solid_angle = 2.0 * PI * (1.0 - SUN_RADIUS.cos());
+        let mut irradiance = sky.sun_radiance * sun_solid_angle * sun_theta.cos();
+
+        let (n_theta, n_phi) = (16, 64);
+        let (d_theta, d_phi) = (PI / 2.0 / n_theta as f64, 2.0 * PI / n_phi as f64);
+        for i in 0..n_theta {
+            let theta = (i as f64 + 0.5) * d_theta;
+            for j in 0..n_phi {
+                let phi = (j as f64 + 0.5) * d_phi;
+                let direction = Vec3::new(
+                    theta.sin() * phi.cos(),
+                    theta.cos(),
+                    theta.sin() * phi.sin(),
+                );
+
+                irradiance +=
+                    sky.sky_radiance(&direction) * (theta.cos() * theta.sin() * d_theta * d_phi);
+            }
+        }
+
+        sky.ground = ground_albedo * irradiance / PI;
+
+        sky
+    }
+
+    /// Radiance arriving along the opposite of direction, Including the sun disk
+    #[allow(dead_code)]
+    pub fn value(&self, direction: &Vec3) -> Vec3 {
+        let direction = direction.unit_vector();
+
+        if direction.dot(&self.sun_direction) >= SUN_RADIUS.cos() {
+            self.sky_radiance(&direction) + self.sun_radiance
+        } else {
+            self.sky_radiance(&direction)
+        }
+    }
+
+    /// Bakes the sky into a width x height environment map so it can be importance sampled.
+    /// The sun is usually smaller than a single texel, So all of its light is put into the
+    /// texel it falls in instead
+    pub fn environment_map(&self, width: usize, height: usize) -> EnvironmentMap {
+        let mut pixels = Vec::with_capacity(width * height);
+
+        for j in 0..height {
+            let t = (j as f64 + 0.5) / height as f64;
+            for i in 0..width {
+                let s = (i as f64 + 0.5) / width as f64;
+                pixels.push(self.sky_radiance(&uv_to_direction(s, 1.0 - t)));
+            }
+        }
+
+        let (u, v) = direction_to_uv(&self.sun_direction);
+        let i = ((u * width as f64) as usize).min(width - 1);
+        let j = (((1.0 - v) * height as f64) as usize).min(height - 1);
+
+        let t = (j as f64 + 0.5) / height as f64;
+        let texel_solid_angle = (2.0 * PI / width as f64) * (PI / height as f64) * (PI * t).sin();
+        let sun_solid_angle = 2.0 * PI * (1.0 - SUN_RADIUS.cos());
+
+        pixels[j * width + i] += self.sun_radiance * (sun_solid_angle / texel_solid_angle);
+
+        EnvironmentMap::new(ImageTexture::from_pixels(width, height, &pixels))
+    }
+
+    fn sky_radiance(&self, direction: &Vec3) -> Vec3 {
+        if direction.y() < 0.0 {
+            return self.ground;
+        }
+
+        let theta = direction.y().clamp(0.0, 1.0).acos();
+        let gamma = direction.dot(&self.sun_direction).clamp(-1.0, 1.0).acos();
+
+        let [luminance, x, y] = [0, 1, 2].map(|i| {
+            self.zenith[i] * perez(&self.coefficients[i], theta, gamma)
+                / perez(&self.coefficients[i], 0.0, self.sun_theta)
+        });
+
+        xyy_to_rgb(x, y, luminance * SCALE)
+    }
+}
+
+fn perez(&[a, b, c, d, e]: &[f64; 5], theta: f64, gamma: f64) -> f64 {
+    let cos_theta = theta.cos().max(0.001);
+    let cos_gamma = gamma.cos();
+
+    (1.0 + a * (b / cos_theta).exp()) * (1.0 + c * (d * gamma).exp() + e * cos_gamma * cos_gamma)
+}
+
+// Sunlight left after Rayleigh(molecules) & Mie(aerosols) scattering along the way
+// through the atmosphere, Using the approximations in the appendix of the paper
+fn sun_radiance(sun_theta: f64, turbidity: f64) -> Vec3 {
+    // Relative optical mass of the air between the ground & the sun
+    let m = 1.0 / (sun_theta.cos() + 0.15 * (93.885 - sun_theta.to_degrees()).powf(-1.253));
+    let beta = 0.04608 * turbidity - 0.04586;
+
+    let [r, g, b] = RGB_WAVELENGTHS.map(|lambda: f64| {
+        let rayleigh = (-0.008735 * m * lambda.powf(-4.08)).exp();
+        let aerosol = (-m * beta * lambda.powf(-1.3)).exp();
+
+        rayleigh * aerosol
+    });
+
+    Vec3::new(r, g, b) * (SUN_LUMINANCE * SCALE)
+}
+
+fn xyy_to_rgb(x: f64, y: f64, luminance: f64) -> Vec3 {
+    if y <= 0.0 {
+        return Vec3::splat(0.0);
+    }
+
+    let cx = x / y * luminance;
+    let cy = luminance;
+    let cz = (1.0 - x - y) / y * luminance;
+
+    Vec3::new(
+        (3.2406 * cx - 1.5372 * cy - 0.4986 * cz).max(0.0),
+        (-0.9689 * cx + 1.8758 * cy + 0.0415 * cz).max(0.0),
+        (0.0557 * cx - 0.2040 * cy + 1.0570 * cz).max(0.0),
+    )
+}
@@ -3,12 +3,16 @@ use {
     rand::Rng,
 };
 
+#[derive(Clone)]
 pub struct Camera {
     origin: Vec3,
     horizontal: Vec3,
     vertical: Vec3,
     lower_left_corner: Vec3,
     lens_radius: f64,
+    // tan(vertical_fov / 2)
+    half_height: f64,
+    half_width: f64,
 
     // position vectors
     u: Vec3,
@@ -60,6 +64,8 @@ impl Camera {
             vertical,
             lower_left_corner,
             lens_radius,
+            half_height,
+            half_width,
             u,
             v,
             w,
@@ -68,6 +74,29 @@ impl Camera {
         }
     }
 
+    /// Angle covered by a single pixel when the image is image_height pixels tall
+    pub fn pixel_spread_angle(&self, image_height: usize) -> f64 {
+        (2.0 * self.half_height / image_height as f64).atan()
+    }
+
+    /// Where a ray going along direction from the camera would land on the image.
+    /// (0, 0) is the bottom left corner & (1, 1) is the top right one.
+    /// None when direction points behind the camera
+    pub fn screen_position(&self, direction: &Vec3) -> Option<(f64, f64)> {
+        let depth = -direction.dot(&self.w);
+        if depth <= 0.0 {
+            return None;
+        }
+
+        let x = direction.dot(&self.u) / depth;
+        let y = direction.dot(&self.v) / depth;
+
+        Some((
+            0.5 + x / (2.0 * self.half_width),
+            0.5 + y / (2.0 * self.half_height),
+        ))
+    }
+
     pub fn get_ray<R: Rng + ?Sized>(&self, u: f64, v: f64, rng: &mut R) -> Ray {
         let rd = random_in_unit_disk(rng) * self.lens_radius;
         let offset = self.u * rd.x() + self.v * rd.y();
@@ -0,0 +1,97 @@
+use std::sync::Arc;
+
+use rand::{prelude::SmallRng, SeedableRng};
+
+use crate::{
+    background::Constant,
+    demos::{Demo, ParallelHit},
+    hitable::{
+        shapes::{RectBuilder, Sphere},
+        BvhNode, Hitable,
+    },
+    materials::{Dielectric, Lambertian, MaterialBuilder, Metal},
+    texture::{Checker, ImageTexture, Solid},
+    types::Vec3,
+    Background, Camera,
+};
+
+pub struct AlphaCutout {}
+
+impl Demo for AlphaCutout {
+    type DemoT = BvhNode<Arc<dyn ParallelHit>>;
+
+    fn name(&self) -> &'static str {
+        "alpha_cutout"
+    }
+
+    fn background(&self, _camera: &Camera) -> Box<dyn Background> {
+        Box::new(Constant::new(Vec3::new(0.7, 0.8, 1.0)))
+    }
+
+    fn world(&self) -> Self::DemoT {
+        let mut world: Vec<Arc<dyn ParallelHit>> = Vec::with_capacity(5);
+
+        let mut rng = rand::thread_rng();
+        let mut rng = SmallRng::from_rng(&mut rng).unwrap();
+
+        world.push(Arc::new(Sphere::new(
+            Vec3::new(0.0, -1000.0, 0.0),
+            1000.0,
+            Lambertian::new(Checker::new(
+                Solid::new(Vec3::new(0.2, 0.3, 0.1)),
+                Solid::new(Vec3::new(0.9, 0.9, 0.9)),
+            )),
+        )));
+
+        let fence = match ImageTexture::from_filename("assets/fence.png") {
+            Ok(v) => v,
+            Err(e) => panic!("error in creating image texture: {}", e),
+        };
+
+        // The fence is a single rectangle, The gaps between the boards come from the alpha channel
+        world.push(Arc::new(
+            RectBuilder
+                .x(-8.0..=8.0)
+                .y(0.0..=4.0)
+                .z(2.0)
+                .material(Lambertian::new(fence.clone()))
+                .alpha_mask(fence),
+        ));
+
+        world.push(Arc::new(Sphere::new(
+            Vec3::new(-3.0, 1.5, -1.0),
+            1.5,
+            Lambertian::new(Solid::new(Vec3::new(0.7, 0.3, 0.1))),
+        )));
+        world.push(Arc::new(Sphere::new(
+            Vec3::new(0.5, 1.5, -2.0),
+            1.5,
+            Metal::with_fuzz(Vec3::new(0.8, 0.8, 0.9), 0.0),
+        )));
+        world.push(Arc::new(Sphere::new(
+            Vec3::new(3.5, 1.5, -0.5),
+            1.5,
+            Dielectric::new(1.5),
+        )));
+
+        BvhNode::new(&mut rng, &mut world, 0.0, 1.0)
+    }
+
+    fn camera(&self, aspect_ratio: f64) -> Camera {
+        let lookfrom = Vec3::new(2.0, 3.0, 16.0);
+        let lookat = Vec3::new(0.0, 1.5, 0.0);
+        let aperture = 0.0;
+        let focus_distance = 16.0;
+        Camera::new(
+            lookfrom,
+            lookat,
+            Vec3::new(0.0, 1.0, 0.0),
+            35.0,
+            aspect_ratio,
+            aperture,
+            focus_distance,
+            0.0,
+            1.0,
+        )
+    }
+}
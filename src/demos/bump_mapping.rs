@@ -0,0 +1,107 @@
+use std::sync::Arc;
+
+use rand::{prelude::SmallRng, SeedableRng};
+
+use crate::{
+    background::Constant,
+    demos::{Demo, ParallelHit},
+    hitable::{
+        shapes::{RectBuilder, Sphere},
+        BvhNode,
+    },
+    materials::{BumpMap, Lambertian, MaterialBuilder, Metal, NormalMap},
+    texture::{Filter, ImageTexture, PerlinNoise, Solid, Wrap},
+    types::Vec3,
+    Background, Camera,
+};
+
+pub struct BumpMapping {}
+
+impl Demo for BumpMapping {
+    type DemoT = BvhNode<Arc<dyn ParallelHit>>;
+
+    fn name(&self) -> &'static str {
+        "bump_mapping"
+    }
+
+    fn background(&self, _camera: &Camera) -> Box<dyn Background> {
+        Box::new(Constant::new(Vec3::new(0.7, 0.8, 1.0)))
+    }
+
+    fn world(&self) -> Self::DemoT {
+        let mut world: Vec<Arc<dyn ParallelHit>> = Vec::with_capacity(4);
+
+        let mut rng = rand::thread_rng();
+        let mut rng = SmallRng::from_rng(&mut rng).unwrap();
+
+        // Rough stucco ground
+        world.push(Arc::new(Sphere::new(
+            Vec3::new(0.0, -1000.0, 0.0),
+            1000.0,
+            BumpMap::new(
+                Lambertian::new(Solid::new(Vec3::splat(0.8))),
+                PerlinNoise::with_scale(&mut rng, 4.0),
+                0.02,
+            ),
+        )));
+
+        let bricks_normal = match ImageTexture::from_filename_linear("assets/bricks_normal.png") {
+            Ok(v) => v
+                .with_filter(Filter::Bilinear)
+                .with_wrap(Wrap::Repeat)
+                .with_uv_transform((2.0, 2.0), (0.0, 0.0))
+                .with_mipmaps(),
+            Err(e) => panic!("error in creating image texture: {}", e),
+        };
+        world.push(Arc::new(
+            RectBuilder
+                .x(-8.0..=8.0)
+                .y(0.0..=8.0)
+                .z(-4.0)
+                .material(NormalMap::new(
+                    Lambertian::new(Solid::new(Vec3::new(0.6, 0.25, 0.15))),
+                    bricks_normal,
+                )),
+        ));
+
+        let earth_texture = match ImageTexture::from_filename("assets/earthmap.jpg") {
+            Ok(v) => v,
+            Err(e) => panic!("error in creating image texture: {}", e),
+        };
+        world.push(Arc::new(Sphere::new(
+            Vec3::new(-2.5, 2.0, 0.0),
+            2.0,
+            BumpMap::new(Lambertian::new(earth_texture.clone()), earth_texture, 0.05),
+        )));
+
+        world.push(Arc::new(Sphere::new(
+            Vec3::new(2.5, 2.0, 0.0),
+            2.0,
+            BumpMap::new(
+                Metal::with_fuzz(Vec3::new(0.8, 0.8, 0.9), 0.05),
+                PerlinNoise::with_scale(&mut rng, 8.0),
+                0.01,
+            ),
+        )));
+
+        BvhNode::new(&mut rng, &mut world, 0.0, 1.0)
+    }
+
+    fn camera(&self, aspect_ratio: f64) -> Camera {
+        let lookfrom = Vec3::new(0.0, 4.0, 16.0);
+        let lookat = Vec3::new(0.0, 2.0, 0.0);
+        let aperture = 0.0;
+        let focus_distance = 16.0;
+        Camera::new(
+            lookfrom,
+            lookat,
+            Vec3::new(0.0, 1.0, 0.0),
+            30.0,
+            aspect_ratio,
+            aperture,
+            focus_distance,
+            0.0,
+            1.0,
+        )
+    }
+}
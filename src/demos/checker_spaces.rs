@@ -0,0 +1,107 @@
+use std::sync::Arc;
+
+use rand::{prelude::SmallRng, SeedableRng};
+
+use crate::{
+    background::Constant,
+    demos::{Demo, ParallelHit},
+    hitable::{
+        shapes::{Cuboid, Sphere},
+        BvhNode, Hitable,
+    },
+    materials::Lambertian,
+    texture::{Checker, Solid, UvChecker},
+    types::Vec3,
+    Background, Camera,
+};
+
+pub struct CheckerSpaces {}
+
+impl Demo for CheckerSpaces {
+    type DemoT = BvhNode<Arc<dyn ParallelHit>>;
+
+    fn name(&self) -> &'static str {
+        "checker_spaces"
+    }
+
+    fn background(&self, _camera: &Camera) -> Box<dyn Background> {
+        Box::new(Constant::new(Vec3::new(0.7, 0.8, 1.0)))
+    }
+
+    fn world(&self) -> Self::DemoT {
+        let mut world: Vec<Arc<dyn ParallelHit>> = Vec::with_capacity(4);
+
+        let mut rng = rand::thread_rng();
+        let mut rng = SmallRng::from_rng(&mut rng).unwrap();
+
+        let white = Solid::new(Vec3::splat(0.9));
+        let red = Solid::new(Vec3::new(0.7, 0.1, 0.1));
+
+        world.push(Arc::new(Sphere::new(
+            Vec3::new(0.0, -1000.0, 0.0),
+            1000.0,
+            Lambertian::new(Checker::new(
+                Solid::new(Vec3::new(0.2, 0.3, 0.1)),
+                white.clone(),
+            )),
+        )));
+
+        // Squares are cut by the world space pattern wherever the box ends up
+        world.push(Arc::new(
+            Cuboid::new(
+                Vec3::splat(-1.0),
+                Vec3::splat(1.0),
+                Lambertian::new(
+                    Checker::new(white.clone(), red.clone())
+                        .with_frequency(1.5 * std::f64::consts::PI),
+                ),
+            )
+            .rotate_y(30.0)
+            .rotate_x(-20.0)
+            .translate(Vec3::new(-3.5, 1.6, 0.0)),
+        ));
+
+        // Same box with the pattern stuck to it, Every face has whole squares
+        world.push(Arc::new(
+            Cuboid::new(
+                Vec3::splat(-1.0),
+                Vec3::splat(1.0),
+                Lambertian::new(
+                    Checker::new(white.clone(), red.clone())
+                        .with_frequency(1.5 * std::f64::consts::PI)
+                        .in_object_space(),
+                ),
+            )
+            .rotate_y(30.0)
+            .rotate_x(-20.0)
+            .translate(Vec3::new(0.0, 1.6, 0.0)),
+        ));
+
+        // Squares follow the latitude & longitude lines
+        world.push(Arc::new(Sphere::new(
+            Vec3::new(3.5, 1.5, 0.0),
+            1.5,
+            Lambertian::new(UvChecker::new(white, red).with_repeat((16.0, 8.0))),
+        )));
+
+        BvhNode::new(&mut rng, &mut world, 0.0, 1.0)
+    }
+
+    fn camera(&self, aspect_ratio: f64) -> Camera {
+        let lookfrom = Vec3::new(0.0, 4.0, 16.0);
+        let lookat = Vec3::new(0.0, 1.5, 0.0);
+        let aperture = 0.0;
+        let focus_distance = 16.0;
+        Camera::new(
+            lookfrom,
+            lookat,
+            Vec3::new(0.0, 1.0, 0.0),
+            35.0,
+            aspect_ratio,
+            aperture,
+            focus_distance,
+            0.0,
+            1.0,
+        )
+    }
+}
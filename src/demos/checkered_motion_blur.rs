@@ -1,4 +1,5 @@
 use crate::{
+    background::VerticalGradient,
     demos::{Demo, ParallelHit},
     hitable::{
         shapes::{MovingSphere, Sphere},
@@ -7,7 +8,7 @@ use crate::{
     materials::{Dielectric, Lambertian, Metal},
     texture::{Checker, Solid},
     types::Vec3,
-    Camera,
+    Background, Camera,
 };
 use rand::{rngs::SmallRng, Rng, SeedableRng};
 use std::sync::Arc;
@@ -21,8 +22,11 @@ impl Demo for CheckeredMotionBlur {
         "checkered_motion_blur"
     }
 
-    fn get_background(&self) -> Vec3 {
-        Vec3::new(0.7, 0.8, 1.0)
+    fn background(&self, _camera: &Camera) -> Box<dyn Background> {
+        Box::new(VerticalGradient::new(
+            Vec3::new(1.0, 1.0, 1.0),
+            Vec3::new(0.5, 0.7, 1.0),
+        ))
     }
 
     fn world(&self) -> Self::DemoT {
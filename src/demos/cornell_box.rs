@@ -62,13 +62,14 @@ impl Demo for CornellBox {
             1.0,
         )));
 
-        let light = DiffuseLight::new(Solid::new(Vec3::splat(7.0)));
+        let light = DiffuseLight::one_sided(Solid::new(Vec3::splat(7.0)));
         objects.push(Arc::new(
             RectBuilder
                 .x(123.0..=423.0)
                 .z(147.0..=412.0)
                 .y(554.0)
-                .material(light),
+                .material(light)
+                .flip_face(),
         ));
 
         let center1 = Vec3::new(400.0, 400.0, 200.0);
@@ -33,7 +33,7 @@ impl Demo for CornellSmokeAndFog {
         let red = Lambertian::new(Solid::new(Vec3::new(0.65, 0.05, 0.05)));
         let white = Lambertian::new(Solid::new(Vec3::splat(0.73)));
         let green = Lambertian::new(Solid::new(Vec3::new(0.12, 0.45, 0.15)));
-        let light = DiffuseLight::new(Solid::new(Vec3::splat(7.0)));
+        let light = DiffuseLight::one_sided(Solid::new(Vec3::splat(7.0)));
 
         world.push(Arc::new(
             RectBuilder
@@ -55,7 +55,8 @@ impl Demo for CornellSmokeAndFog {
                 .x(113.0..=443.0)
                 .z(127.0..=432.0)
                 .y(554.0)
-                .material(light),
+                .material(light)
+                .flip_face(),
         ));
         world.push(Arc::new(
             RectBuilder
@@ -0,0 +1,123 @@
+use std::sync::Arc;
+
+use rand::{prelude::SmallRng, SeedableRng};
+
+use crate::{
+    background::Sky,
+    demos::{Demo, ParallelHit},
+    hitable::{
+        shapes::{Cuboid, Cylinder, Sphere},
+        volume::ConstantMedium,
+        BvhNode, Hitable, Transform,
+    },
+    materials::{Dielectric, Isotropic, Lambertian, Metal},
+    texture::{Checker, Solid},
+    types::Vec3,
+    Background, Camera,
+};
+
+pub struct CsgShapes {}
+
+impl Demo for CsgShapes {
+    type DemoT = BvhNode<Arc<dyn ParallelHit>>;
+
+    fn name(&self) -> &'static str {
+        "csg_shapes"
+    }
+
+    fn background(&self, _camera: &Camera) -> Box<dyn Background> {
+        let sky = Sky::new(Vec3::new(-1.0, 1.5, 0.8), 3.0, Vec3::splat(0.3));
+
+        Box::new(sky.environment_map(1024, 512))
+    }
+
+    fn world(&self) -> Self::DemoT {
+        let mut world: Vec<Arc<dyn ParallelHit>> = Vec::with_capacity(6);
+
+        let mut rng = rand::thread_rng();
+        let mut rng = SmallRng::from_rng(&mut rng).unwrap();
+
+        world.push(Arc::new(Sphere::new(
+            Vec3::new(0.0, -1000.0, 0.0),
+            1000.0,
+            Lambertian::new(
+                Checker::new(
+                    Solid::new(Vec3::new(0.2, 0.3, 0.1)),
+                    Solid::new(Vec3::splat(0.9)),
+                )
+                .with_frequency(2.0),
+            ),
+        )));
+
+        // Ball with a corner of a box carved out of it, The cut shows the box's material
+        world.push(Arc::new(
+            Sphere::new(
+                Vec3::new(-3.2, 1.2, 0.0),
+                1.2,
+                Lambertian::new(Solid::new(Vec3::new(0.8, 0.3, 0.1))),
+            )
+            .subtract(Cuboid::new(
+                Vec3::new(-3.2, 1.2, 0.0),
+                Vec3::new(-1.5, 3.0, 2.0),
+                Lambertian::new(Solid::new(Vec3::splat(0.9))),
+            )),
+        ));
+
+        // Lens, Overlap of two big glass balls
+        let glass = Dielectric::new(1.5);
+        world.push(Arc::new(
+            Sphere::new(Vec3::new(-1.2, 1.2, 0.0), 1.6, glass.clone()).intersect(Sphere::new(
+                Vec3::new(0.8, 1.2, 0.0),
+                1.6,
+                glass.clone(),
+            )),
+        ));
+
+        // Three cylinders crossing each other, With a ball through the middle of them taken out
+        let metal = Metal::with_fuzz(Vec3::new(0.7, 0.75, 0.8), 0.1);
+        let rod = Arc::new(Cylinder::new(0.4, -1.1, 1.1, metal).capped());
+        let axis = Vec3::new(0.0, 0.0, 1.0);
+        let cross = Transform::new(rod.clone())
+            .union(Transform::new(rod.clone()).with_rotation(axis, 90.0))
+            .union(Transform::new(rod).with_rotation(Vec3::new(1.0, 0.0, 0.0), 90.0))
+            .subtract(Sphere::new(
+                Vec3::new(0.0, 0.0, 0.0),
+                0.55,
+                Lambertian::new(Solid::new(Vec3::new(0.1, 0.4, 0.8))),
+            ));
+        world.push(Arc::new(
+            Transform::new(Arc::new(cross))
+                .with_rotation(Vec3::new(0.0, 1.0, 0.0), 30.0)
+                .with_translation(Vec3::new(2.2, 1.1, 0.0)),
+        ));
+
+        // Fog inside a hollow shell, The ray has to add up its way through both walls
+        let shell = Sphere::new(Vec3::new(-1.0, 0.7, 3.0), 0.7, glass.clone())
+            .subtract(Sphere::new(Vec3::new(-1.0, 0.7, 3.0), 0.45, glass));
+        world.push(Arc::new(ConstantMedium::new(
+            shell,
+            Isotropic::new(Solid::new(Vec3::new(0.9, 0.9, 0.95))),
+            2.0,
+        )));
+
+        BvhNode::new(&mut rng, &mut world, 0.0, 1.0)
+    }
+
+    fn camera(&self, aspect_ratio: f64) -> Camera {
+        let lookfrom = Vec3::new(0.0, 4.0, 12.0);
+        let lookat = Vec3::new(-0.4, 1.0, 0.0);
+        let aperture = 0.0;
+        let focus_distance = 12.0;
+        Camera::new(
+            lookfrom,
+            lookat,
+            Vec3::new(0.0, 1.0, 0.0),
+            45.0,
+            aspect_ratio,
+            aperture,
+            focus_distance,
+            0.0,
+            1.0,
+        )
+    }
+}
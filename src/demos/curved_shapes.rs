@@ -0,0 +1,144 @@
+use std::sync::Arc;
+
+use rand::{prelude::SmallRng, SeedableRng};
+
+use crate::{
+    background::Sky,
+    demos::{Demo, ParallelHit},
+    hitable::{
+        shapes::{Cone, Cylinder, Paraboloid, Sphere, Torus},
+        BvhNode, Transform,
+    },
+    materials::{Dielectric, Lambertian, Metal},
+    texture::{Checker, Solid, UvChecker},
+    types::Vec3,
+    Background, Camera,
+};
+
+pub struct CurvedShapes {}
+
+impl Demo for CurvedShapes {
+    type DemoT = BvhNode<Arc<dyn ParallelHit>>;
+
+    fn name(&self) -> &'static str {
+        "curved_shapes"
+    }
+
+    fn background(&self, _camera: &Camera) -> Box<dyn Background> {
+        let sky = Sky::new(Vec3::new(1.0, 1.2, 0.4), 3.0, Vec3::splat(0.3));
+
+        Box::new(sky.environment_map(1024, 512))
+    }
+
+    fn world(&self) -> Self::DemoT {
+        let mut world: Vec<Arc<dyn ParallelHit>> = Vec::with_capacity(12);
+
+        let mut rng = rand::thread_rng();
+        let mut rng = SmallRng::from_rng(&mut rng).unwrap();
+
+        let checker = Lambertian::new(
+            UvChecker::new(
+                Solid::new(Vec3::new(0.7, 0.1, 0.1)),
+                Solid::new(Vec3::splat(0.9)),
+            )
+            .with_repeat((8.0, 4.0)),
+        );
+
+        world.push(Arc::new(Sphere::new(
+            Vec3::new(0.0, -1000.0, 0.0),
+            1000.0,
+            Lambertian::new(
+                Checker::new(
+                    Solid::new(Vec3::new(0.2, 0.3, 0.1)),
+                    Solid::new(Vec3::splat(0.9)),
+                )
+                .with_frequency(2.0),
+            ),
+        )));
+
+        // Row of columns at the back
+        let column = Arc::new(
+            Cylinder::new(
+                0.35,
+                0.0,
+                4.0,
+                Lambertian::new(Solid::new(Vec3::splat(0.8))),
+            )
+            .capped(),
+        );
+        for x in [-6.0, -3.0, 0.0, 3.0, 6.0] {
+            world.push(Arc::new(
+                Transform::new(column.clone()).with_translation(Vec3::new(x, 0.0, -4.0)),
+            ));
+        }
+
+        // Pipe lying on its side with a quarter cut out of it
+        world.push(Arc::new(
+            Transform::new(Arc::new(
+                Cylinder::new(0.6, -1.5, 1.5, checker.clone()).with_sweep(270.0),
+            ))
+            .with_rotation(Vec3::new(0.0, 1.0, 0.0), -90.0)
+            .with_rotation(Vec3::new(0.0, 0.0, 1.0), 90.0)
+            .with_rotation(Vec3::new(0.0, 1.0, 0.0), 30.0)
+            .with_translation(Vec3::new(-4.0, 0.6, 0.0)),
+        ));
+
+        // Cone with a wedge cut out, The base is closed off
+        world.push(Arc::new(
+            Transform::new(Arc::new(
+                Cone::new(0.9, 2.2, checker.clone())
+                    .with_sweep(300.0)
+                    .capped(),
+            ))
+            .with_rotation(Vec3::new(0.0, 1.0, 0.0), 240.0)
+            .with_translation(Vec3::new(-1.3, 0.0, 0.5)),
+        ));
+
+        // Shiny bowl with a slice taken out
+        world.push(Arc::new(
+            Transform::new(Arc::new(
+                Paraboloid::new(1.0, 0.0, 1.2, Metal::with_fuzz(Vec3::splat(0.85), 0.05))
+                    .with_sweep(300.0),
+            ))
+            .with_rotation(Vec3::new(0.0, 1.0, 0.0), 90.0)
+            .with_translation(Vec3::new(1.3, 0.0, 0.5)),
+        ));
+
+        // Standing ring, A glass ring lying down & a broken one
+        world.push(Arc::new(
+            Transform::new(Arc::new(Torus::new(0.8, 0.25, checker)))
+                .with_rotation(Vec3::new(1.0, 0.0, 0.0), 90.0)
+                .with_translation(Vec3::new(4.0, 1.05, 0.0)),
+        ));
+        world.push(Arc::new(
+            Transform::new(Arc::new(Torus::new(0.7, 0.2, Dielectric::new(1.5))))
+                .with_translation(Vec3::new(0.0, 0.2, 2.5)),
+        ));
+        world.push(Arc::new(
+            Transform::new(Arc::new(
+                Torus::new(0.5, 0.15, Metal::new(Vec3::new(0.9, 0.7, 0.3))).with_sweep(240.0),
+            ))
+            .with_translation(Vec3::new(2.8, 0.15, 2.5)),
+        ));
+
+        BvhNode::new(&mut rng, &mut world, 0.0, 1.0)
+    }
+
+    fn camera(&self, aspect_ratio: f64) -> Camera {
+        let lookfrom = Vec3::new(0.0, 4.0, 13.0);
+        let lookat = Vec3::new(0.0, 1.0, 0.0);
+        let aperture = 0.0;
+        let focus_distance = 13.0;
+        Camera::new(
+            lookfrom,
+            lookat,
+            Vec3::new(0.0, 1.0, 0.0),
+            40.0,
+            aspect_ratio,
+            aperture,
+            focus_distance,
+            0.0,
+            1.0,
+        )
+    }
+}
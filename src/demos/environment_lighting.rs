@@ -0,0 +1,84 @@
+use std::sync::Arc;
+
+use rand::{prelude::SmallRng, SeedableRng};
+
+use crate::{
+    background::EnvironmentMap,
+    demos::{Demo, ParallelHit},
+    hitable::{shapes::Sphere, BvhNode},
+    materials::{Dielectric, Lambertian, Metal},
+    texture::{Checker, Solid},
+    types::Vec3,
+    Background, Camera,
+};
+
+pub struct EnvironmentLighting {}
+
+impl Demo for EnvironmentLighting {
+    type DemoT = BvhNode<Arc<dyn ParallelHit>>;
+
+    fn name(&self) -> &'static str {
+        "environment_lighting"
+    }
+
+    fn background(&self, _camera: &Camera) -> Box<dyn Background> {
+        match EnvironmentMap::from_filename("assets/sky.hdr") {
+            Ok(v) => Box::new(v.with_rotation(-60.0)),
+            Err(e) => panic!("error in loading environment map: {}", e),
+        }
+    }
+
+    fn world(&self) -> Self::DemoT {
+        let mut world: Vec<Arc<dyn ParallelHit>> = Vec::with_capacity(4);
+
+        let mut rng = rand::thread_rng();
+        let mut rng = SmallRng::from_rng(&mut rng).unwrap();
+
+        world.push(Arc::new(Sphere::new(
+            Vec3::new(0.0, -1000.0, 0.0),
+            1000.0,
+            Lambertian::new(Checker::new(
+                Solid::new(Vec3::new(0.2, 0.3, 0.1)),
+                Solid::new(Vec3::new(0.9, 0.9, 0.9)),
+            )),
+        )));
+
+        world.push(Arc::new(Sphere::new(
+            Vec3::new(-4.2, 2.0, 0.0),
+            2.0,
+            Lambertian::new(Solid::new(Vec3::new(0.8, 0.3, 0.2))),
+        )));
+
+        world.push(Arc::new(Sphere::new(
+            Vec3::new(0.0, 2.0, 0.0),
+            2.0,
+            Dielectric::new(1.5),
+        )));
+
+        world.push(Arc::new(Sphere::new(
+            Vec3::new(4.2, 2.0, 0.0),
+            2.0,
+            Metal::new(Vec3::new(0.8, 0.8, 0.85)),
+        )));
+
+        BvhNode::new(&mut rng, &mut world, 0.0, 1.0)
+    }
+
+    fn camera(&self, aspect_ratio: f64) -> Camera {
+        let lookfrom = Vec3::new(0.0, 4.0, 18.0);
+        let lookat = Vec3::new(0.0, 2.0, 0.0);
+        let aperture = 0.0;
+        let focus_distance = 18.0;
+        Camera::new(
+            lookfrom,
+            lookat,
+            Vec3::new(0.0, 1.0, 0.0),
+            35.0,
+            aspect_ratio,
+            aperture,
+            focus_distance,
+            0.0,
+            1.0,
+        )
+    }
+}
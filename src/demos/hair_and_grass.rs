@@ -0,0 +1,161 @@
+use std::sync::Arc;
+
+use rand::{prelude::SmallRng, Rng, SeedableRng};
+
+use crate::{
+    background::Sky,
+    demos::{Demo, ParallelHit},
+    hitable::{
+        shapes::{Curve, Sphere},
+        BvhNode,
+    },
+    materials::{Hair, Lambertian},
+    texture::Solid,
+    types::Vec3,
+    Background, Camera,
+};
+
+pub struct HairAndGrass {}
+
+impl HairAndGrass {
+    /// Strands growing out of a ball, Bending down under their own weight
+    fn hairy_ball(
+        rng: &mut SmallRng,
+        world: &mut Vec<Arc<dyn ParallelHit>>,
+        center: Vec3,
+        radius: f64,
+        strands: usize,
+        length: f64,
+        material: Hair,
+    ) {
+        world.push(Arc::new(Sphere::new(
+            center,
+            radius,
+            Lambertian::new(Solid::new(Vec3::splat(0.05))),
+        )));
+
+        for _ in 0..strands {
+            let direction = loop {
+                let d = Vec3::random_in_range(rng, -1.0..=1.0);
+                if d.sq_len() <= 1.0 && d.sq_len() > 1e-4 && d.y() > -0.3 {
+                    break d.unit_vector();
+                }
+            };
+
+            let root = center + direction * radius * 0.98;
+            let length = length * rng.gen_range(0.8..=1.2);
+            let droop = Vec3::new(0.0, -length * 0.6, 0.0);
+
+            let points = [
+                root,
+                root + direction * (length / 3.0),
+                root + direction * (length * 2.0 / 3.0) + droop * 0.4,
+                root + direction * length + droop,
+            ];
+
+            let strand = Curve::new(points, [0.012, 0.01, 0.007, 0.002], material.clone()).tube();
+            for segment in strand.segments(4) {
+                world.push(Arc::new(segment));
+            }
+        }
+    }
+}
+
+impl Demo for HairAndGrass {
+    type DemoT = BvhNode<Arc<dyn ParallelHit>>;
+
+    fn name(&self) -> &'static str {
+        "hair_and_grass"
+    }
+
+    fn background(&self, _camera: &Camera) -> Box<dyn Background> {
+        let sky = Sky::new(Vec3::new(1.0, 1.0, 1.0), 3.0, Vec3::splat(0.3));
+
+        Box::new(sky.environment_map(1024, 512))
+    }
+
+    fn world(&self) -> Self::DemoT {
+        let mut world: Vec<Arc<dyn ParallelHit>> = Vec::with_capacity(20000);
+
+        let mut rng = rand::thread_rng();
+        let mut rng = SmallRng::from_rng(&mut rng).unwrap();
+
+        world.push(Arc::new(Sphere::new(
+            Vec3::new(0.0, -1000.0, 0.0),
+            1000.0,
+            Lambertian::new(Solid::new(Vec3::new(0.35, 0.25, 0.15))),
+        )));
+
+        // Brown, Blonde & dyed
+        Self::hairy_ball(
+            &mut rng,
+            &mut world,
+            Vec3::new(-2.4, 1.0, 0.0),
+            0.7,
+            3000,
+            0.9,
+            Hair::from_melanin(1.3, 0.0),
+        );
+        Self::hairy_ball(
+            &mut rng,
+            &mut world,
+            Vec3::new(0.0, 1.0, 0.0),
+            0.7,
+            3000,
+            0.9,
+            Hair::from_melanin(0.3, 0.1).with_roughness(0.25, 0.3),
+        );
+        Self::hairy_ball(
+            &mut rng,
+            &mut world,
+            Vec3::new(2.4, 1.0, 0.0),
+            0.7,
+            3000,
+            0.6,
+            Hair::from_color(Vec3::new(0.1, 0.4, 0.6)).with_scale_angle(3.0),
+        );
+
+        // Patch of grass, Flat blades that turn a little as they bend over
+        let grass = Lambertian::new(Solid::new(Vec3::new(0.2, 0.5, 0.1)));
+        for _ in 0..8000 {
+            let root = Vec3::new(rng.gen_range(-4.5..=4.5), 0.0, rng.gen_range(0.8..=3.0));
+            let height = rng.gen_range(0.25..=0.5);
+            let angle = rng.gen_range(0.0..=std::f64::consts::TAU);
+            let (sin, cos) = angle.sin_cos();
+            let bend = Vec3::new(cos, 0.0, sin) * height * rng.gen_range(0.1..=0.5);
+
+            let points = [
+                root,
+                root + Vec3::new(0.0, height / 3.0, 0.0),
+                root + Vec3::new(0.0, height * 2.0 / 3.0, 0.0) + bend * 0.3,
+                root + Vec3::new(0.0, height, 0.0) + bend,
+            ];
+            let facing = Vec3::new(cos, 0.0, sin);
+
+            world.push(Arc::new(
+                Curve::new(points, [0.03, 0.025, 0.015, 0.0], grass.clone())
+                    .oriented(facing, facing + Vec3::new(-sin, 0.0, cos) * 0.5),
+            ));
+        }
+
+        BvhNode::new(&mut rng, &mut world, 0.0, 1.0)
+    }
+
+    fn camera(&self, aspect_ratio: f64) -> Camera {
+        let lookfrom = Vec3::new(0.0, 2.5, 8.0);
+        let lookat = Vec3::new(0.0, 0.8, 0.0);
+        let aperture = 0.0;
+        let focus_distance = 8.0;
+        Camera::new(
+            lookfrom,
+            lookat,
+            Vec3::new(0.0, 1.0, 0.0),
+            45.0,
+            aspect_ratio,
+            aperture,
+            focus_distance,
+            0.0,
+            1.0,
+        )
+    }
+}
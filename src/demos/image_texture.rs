@@ -3,12 +3,13 @@ use std::sync::Arc;
 use rand::{prelude::SmallRng, SeedableRng};
 
 use crate::{
+    background::Constant,
     demos::{Demo, ParallelHit},
     hitable::shapes::Sphere,
     materials::Lambertian,
-    texture::ImageTexture,
+    texture::{Filter, ImageTexture},
     types::Vec3,
-    BvhNode, Camera,
+    Background, BvhNode, Camera,
 };
 
 pub struct ImageTextureDemo {}
@@ -20,8 +21,8 @@ impl Demo for ImageTextureDemo {
         "image_texture"
     }
 
-    fn get_background(&self) -> Vec3 {
-        Vec3::new(0.7, 0.8, 1.0)
+    fn background(&self, _camera: &Camera) -> Box<dyn Background> {
+        Box::new(Constant::new(Vec3::new(0.7, 0.8, 1.0)))
     }
 
     fn world(&self) -> Self::DemoT {
@@ -31,7 +32,7 @@ impl Demo for ImageTextureDemo {
         let mut rng = SmallRng::from_rng(&mut rng).unwrap();
 
         let earth_texture = match ImageTexture::from_filename("assets/earthmap.jpg") {
-            Ok(v) => v,
+            Ok(v) => v.with_filter(Filter::Bicubic).with_mipmaps(),
             Err(e) => panic!("error in creating image texture: {}", e),
         };
 
@@ -9,7 +9,7 @@ use crate::{
         Hitable,
     },
     materials::{DiffuseLight, Lambertian, MaterialBuilder},
-    texture::Solid,
+    texture::{noise::Fbm, ColorRamp, Marble, Perlin, Solid},
     types::Vec3,
     BvhNode, Camera,
 };
@@ -32,7 +32,7 @@ impl Demo for Instances {
         let red = Lambertian::new(Solid::new(Vec3::new(0.65, 0.05, 0.05)));
         let white = Lambertian::new(Solid::new(Vec3::splat(0.73)));
         let green = Lambertian::new(Solid::new(Vec3::new(0.12, 0.45, 0.15)));
-        let light = DiffuseLight::new(Solid::new(Vec3::splat(15.0)));
+        let light = DiffuseLight::one_sided(Solid::new(Vec3::splat(15.0)));
 
         world.push(Arc::new(
             RectBuilder
@@ -53,7 +53,8 @@ impl Demo for Instances {
                 .x(213.0..=343.0)
                 .z(227.0..=332.0)
                 .y(554.0)
-                .material(light),
+                .material(light)
+                .flip_face(),
         ));
 
         world.push(Arc::new(
@@ -78,12 +79,27 @@ impl Demo for Instances {
                 .material(white.clone()),
         ));
 
+        // Marble veins stay on the box as it's rotated & moved into place
+        let marble = Lambertian::new(
+            Marble::new(
+                Fbm::new(Perlin::new(&mut rng), 5),
+                ColorRamp::new(vec![
+                    (0.0, Vec3::new(0.3, 0.3, 0.32)),
+                    (1.0, Vec3::splat(0.73)),
+                ]),
+            )
+            .with_axis(Vec3::new(0.02, 0.05, 0.0))
+            .with_distortion(6.0)
+            .with_scale(0.02)
+            .in_object_space(),
+        );
+
         // Add the two boxes
         world.push(Arc::new(
             Cuboid::new(
                 Vec3::new(0.0, 0.0, 0.0),
                 Vec3::new(165.0, 330.0, 165.0),
-                white.clone(),
+                marble,
             )
             .rotate_y(15.0)
             .translate(Vec3::new(265.0, 0.0, 295.0)),
@@ -1,7 +1,8 @@
 use crate::{
-    hitable::{hitable_list::HitableList, BvhNode, Hitable},
+    background::Constant,
+    hitable::{hitable_list::HitableList, shapes::ParallelSample, BvhNode, Hitable},
     types::{Color, Vec3},
-    Camera, HORIZONTAL_PARTITION, VERTICAL_PARTITION,
+    Background, Camera, HORIZONTAL_PARTITION, VERTICAL_PARTITION,
 };
 use rand::{rngs::SmallRng, Rng, SeedableRng};
 use rayon::prelude::*;
@@ -12,22 +13,56 @@ use std::{
     sync::{Arc, Mutex},
 };
 
+mod alpha_cutout;
+mod bump_mapping;
+mod checker_spaces;
 mod checkered_motion_blur;
 mod cornell_box;
 mod cornell_smoke_and_fog;
+mod csg_shapes;
+mod curved_shapes;
+mod environment_lighting;
+mod hair_and_grass;
 mod image_texture;
 mod instances;
+mod motion_transforms;
 mod perlin_noise_ball;
+mod planar_shapes;
+mod procedural_textures;
+mod sdf_shapes;
 mod simple_light;
+mod subdivision_surfaces;
+mod subsurface_scattering;
+mod terrain;
+mod texture_nodes;
+mod thin_film_interference;
+mod transform_instances;
 mod two_spheres;
 
+pub use alpha_cutout::AlphaCutout;
+pub use bump_mapping::BumpMapping;
+pub use checker_spaces::CheckerSpaces;
 pub use checkered_motion_blur::CheckeredMotionBlur;
 pub use cornell_box::CornellBox;
 pub use cornell_smoke_and_fog::CornellSmokeAndFog;
+pub use csg_shapes::CsgShapes;
+pub use curved_shapes::CurvedShapes;
+pub use environment_lighting::EnvironmentLighting;
+pub use hair_and_grass::HairAndGrass;
 pub use image_texture::ImageTextureDemo;
 pub use instances::Instances;
+pub use motion_transforms::MotionTransforms;
 pub use perlin_noise_ball::PerlinNoiseBall;
+pub use planar_shapes::PlanarShapes;
+pub use procedural_textures::ProceduralTextures;
+pub use sdf_shapes::SdfShapes;
 pub use simple_light::SimpleLight;
+pub use subdivision_surfaces::SubdivisionSurfaces;
+pub use subsurface_scattering::SubsurfaceScattering;
+pub use terrain::Terrain;
+pub use texture_nodes::TextureNodes;
+pub use thin_film_interference::ThinFilmInterference;
+pub use transform_instances::TransformInstances;
 pub use two_spheres::TwoSpheres;
 
 #[derive(Debug)]
@@ -64,11 +99,26 @@ pub trait Demo: Send + Sync {
 
     fn camera(&self, aspect_ratio: f64) -> Camera;
 
-    fn get_background(&self) -> Vec3 {
-        Vec3::new(0.0, 0.0, 0.0)
+    /// camera is the one the image is rendered with, For backgrounds that depend on the view
+    fn background(&self, _camera: &Camera) -> Box<dyn Background> {
+        Box::new(Constant::new(Vec3::new(0.0, 0.0, 0.0)))
     }
 
-    fn render_chunk(&self, chunk: &mut Chunk, camera: &Camera, world: &Self::DemoT, samples: u16) {
+    /// Area lights that are also in the world, Some of the rays bouncing off diffuse
+    /// surfaces are sent straight at them which cuts down the noise a lot for small lights
+    fn lights(&self) -> Vec<Arc<dyn ParallelSample>> {
+        Vec::new()
+    }
+
+    fn render_chunk(
+        &self,
+        chunk: &mut Chunk,
+        camera: &Camera,
+        world: &Self::DemoT,
+        background: &dyn Background,
+        lights: &[Arc<dyn ParallelSample>],
+        samples: u16,
+    ) {
         let &mut Chunk {
             num: _,
             x,
@@ -82,7 +132,7 @@ pub trait Demo: Send + Sync {
         let mut offset = 0;
         let mut rng = rand::thread_rng();
         let mut rng = SmallRng::from_rng(&mut rng).unwrap();
-        let background = self.get_background();
+        let spread = camera.pixel_spread_angle(y);
 
         assert!(buffer.len() >= nx * ny * 4);
 
@@ -93,8 +143,8 @@ pub trait Demo: Send + Sync {
                     let u = (i as f64 + rng.gen::<f64>()) / x as f64;
                     let v = (j as f64 + rng.gen::<f64>()) / y as f64;
 
-                    let ray = camera.get_ray(u, v, &mut rng);
-                    color += ray.color(world, &mut rng, &background, 0);
+                    let ray = camera.get_ray(u, v, &mut rng).with_spread(spread);
+                    color += ray.color(world, &mut rng, background, lights, 0);
                 }
 
                 color /= samples as f64;
@@ -115,6 +165,8 @@ pub trait Demo: Send + Sync {
         // by vertical/horizontal partitions in the chunks around the edges
         // but umm, i'll just ignore those for now.
         let camera = self.camera(delta_x as f64 / delta_y as f64);
+        let background = self.background(&camera);
+        let lights = self.lights();
         let buf = Arc::new(Mutex::new(buf));
 
         (0..VERTICAL_PARTITION).into_par_iter().for_each(|j| {
@@ -147,7 +199,14 @@ pub trait Demo: Send + Sync {
                 };
 
                 println!("{}", chunk);
-                self.render_chunk(&mut chunk, &camera, &world, samples);
+                self.render_chunk(
+                    &mut chunk,
+                    &camera,
+                    &world,
+                    background.as_ref(),
+                    &lights,
+                    samples,
+                );
 
                 let mut buf = buf.lock().unwrap();
                 let mut temp_offset = 0;
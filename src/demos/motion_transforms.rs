@@ -0,0 +1,129 @@
+use std::sync::Arc;
+
+use rand::{prelude::SmallRng, Rng, SeedableRng};
+
+use crate::{
+    background::VerticalGradient,
+    demos::{Demo, ParallelHit},
+    hitable::{
+        shapes::{Cuboid, Sphere},
+        BvhNode, Keyframe, MotionTransform,
+    },
+    materials::{Dielectric, Lambertian, Metal},
+    texture::{Checker, Solid, UvChecker},
+    types::Vec3,
+    Background, Camera,
+};
+
+pub struct MotionTransforms {}
+
+impl Demo for MotionTransforms {
+    type DemoT = BvhNode<Arc<dyn ParallelHit>>;
+
+    fn name(&self) -> &'static str {
+        "motion_transforms"
+    }
+
+    fn background(&self, _camera: &Camera) -> Box<dyn Background> {
+        Box::new(VerticalGradient::new(
+            Vec3::new(1.0, 1.0, 1.0),
+            Vec3::new(0.5, 0.7, 1.0),
+        ))
+    }
+
+    fn world(&self) -> Self::DemoT {
+        let mut world: Vec<Arc<dyn ParallelHit>> = Vec::with_capacity(4);
+
+        let mut rng = rand::thread_rng();
+        let mut rng = SmallRng::from_rng(&mut rng).unwrap();
+
+        world.push(Arc::new(Sphere::new(
+            Vec3::new(0.0, -1000.0, 0.0),
+            1000.0,
+            Lambertian::new(
+                Checker::new(
+                    Solid::new(Vec3::new(0.2, 0.3, 0.1)),
+                    Solid::new(Vec3::new(0.9, 0.9, 0.9)),
+                )
+                .with_frequency(2.0),
+            ),
+        )));
+
+        // Box tumbling around a tilted axis
+        let cuboid = Arc::new(Cuboid::new(
+            Vec3::splat(-1.0),
+            Vec3::splat(1.0),
+            Lambertian::new(UvChecker::new(
+                Solid::new(Vec3::new(0.7, 0.1, 0.1)),
+                Solid::new(Vec3::splat(0.9)),
+            )),
+        ));
+        world.push(Arc::new(MotionTransform::new(
+            cuboid,
+            Keyframe::new().with_translation(Vec3::new(-3.0, 1.0, 0.0)),
+            Keyframe::new()
+                .with_translation(Vec3::new(-3.0, 1.0, 0.0))
+                .with_rotation(Vec3::new(0.3, 1.0, 0.2), 15.0),
+            0.0,
+            1.0,
+        )));
+
+        // A whole cluster of spheres sliding sideways together
+        let mut cluster: Vec<Arc<dyn ParallelHit>> = Vec::with_capacity(20);
+        for _ in 0..20 {
+            let center = Vec3::random_in_range(&mut rng, -0.8..=0.8);
+            cluster.push(Arc::new(Sphere::new(
+                center,
+                0.25,
+                Metal::with_fuzz(
+                    Vec3::new(
+                        rng.gen_range(0.5..1.0),
+                        rng.gen_range(0.5..1.0),
+                        rng.gen_range(0.5..1.0),
+                    ),
+                    0.1,
+                ),
+            )));
+        }
+        world.push(Arc::new(MotionTransform::new(
+            Arc::new(BvhNode::new(&mut rng, &mut cluster, 0.0, 1.0)),
+            Keyframe::new().with_translation(Vec3::new(-0.15, 1.1, 0.0)),
+            Keyframe::new().with_translation(Vec3::new(0.15, 1.1, 0.0)),
+            0.0,
+            1.0,
+        )));
+
+        // Glass ball that inflates & stretches upwards
+        world.push(Arc::new(MotionTransform::new(
+            Arc::new(Sphere::new(Vec3::splat(0.0), 1.0, Dielectric::new(1.5))),
+            Keyframe::new()
+                .with_translation(Vec3::new(3.0, 0.8, 0.0))
+                .with_scale(Vec3::splat(0.8)),
+            Keyframe::new()
+                .with_translation(Vec3::new(3.0, 1.0, 0.0))
+                .with_scale(Vec3::new(0.9, 1.0, 0.9)),
+            0.0,
+            1.0,
+        )));
+
+        BvhNode::new(&mut rng, &mut world, 0.0, 1.0)
+    }
+
+    fn camera(&self, aspect_ratio: f64) -> Camera {
+        let lookfrom = Vec3::new(0.0, 3.0, 14.0);
+        let lookat = Vec3::new(0.0, 1.0, 0.0);
+        let aperture = 0.0;
+        let focus_distance = 14.0;
+        Camera::new(
+            lookfrom,
+            lookat,
+            Vec3::new(0.0, 1.0, 0.0),
+            30.0,
+            aspect_ratio,
+            aperture,
+            focus_distance,
+            0.0,
+            1.0,
+        )
+    }
+}
@@ -3,12 +3,13 @@ use std::sync::Arc;
 use rand::{prelude::SmallRng, SeedableRng};
 
 use crate::{
+    background::Sky,
     demos::{Demo, ParallelHit},
     hitable::{shapes::Sphere, BvhNode},
     materials::Lambertian,
-    texture::PerlinNoise,
+    texture::{Perlin, PerlinNoise},
     types::Vec3,
-    Camera,
+    Background, Camera,
 };
 
 pub struct PerlinNoiseBall {}
@@ -20,8 +21,10 @@ impl Demo for PerlinNoiseBall {
         "perlin_noise"
     }
 
-    fn get_background(&self) -> Vec3 {
-        Vec3::new(0.7, 0.8, 1.0)
+    fn background(&self, _camera: &Camera) -> Box<dyn Background> {
+        let sky = Sky::new(Vec3::new(1.0, 1.2, 0.4), 3.0, Vec3::splat(0.3));
+
+        Box::new(sky.environment_map(1024, 512))
     }
 
     fn world(&self) -> Self::DemoT {
@@ -30,16 +33,23 @@ impl Demo for PerlinNoiseBall {
         let mut rng = rand::thread_rng();
         let mut rng = SmallRng::from_rng(&mut rng).unwrap();
 
+        // Both spheres share one generator, The ball's pattern also churns during the exposure
+        let noise = Arc::new(Perlin::with_seed(2002));
+
         world.push(Arc::new(Sphere::new(
             Vec3::new(0.0, -1000.0, 0.0),
             1000.0,
-            Lambertian::new(PerlinNoise::with_scale(&mut rng, 4.0)),
+            Lambertian::new(PerlinNoise::from_perlin(noise.clone(), 4.0)),
         )));
 
         world.push(Arc::new(Sphere::new(
             Vec3::new(0.0, 2.0, 0.0),
             2.0,
-            Lambertian::new(PerlinNoise::with_scale(&mut rng, 4.0)),
+            Lambertian::new(
+                PerlinNoise::from_perlin(noise, 4.0)
+                    .with_depth(5)
+                    .with_speed(0.5),
+            ),
         )));
 
         BvhNode::new(&mut rng, &mut world, 0.0, 1.0)
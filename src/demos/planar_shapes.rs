@@ -0,0 +1,138 @@
+use std::sync::Arc;
+
+use rand::{prelude::SmallRng, SeedableRng};
+
+use crate::{
+    background::Constant,
+    demos::{Demo, ParallelHit},
+    hitable::{
+        shapes::{Disk, ParallelSample, Quad, Triangle},
+        BvhNode,
+    },
+    materials::{DiffuseLight, Lambertian, Metal},
+    texture::{Solid, UvChecker},
+    types::Vec3,
+    Background, Camera,
+};
+
+pub struct PlanarShapes {}
+
+impl PlanarShapes {
+    /// Light panel hanging at an angle & a round light, Both only shine from the front
+    fn light_shapes() -> (Quad<DiffuseLight<Solid>>, Disk<DiffuseLight<Solid>>) {
+        (
+            Quad::new(
+                Vec3::new(-2.0, 6.0, -1.0),
+                Vec3::new(4.0, 0.0, 0.0),
+                Vec3::new(0.0, 1.0, 2.0),
+                DiffuseLight::one_sided(Solid::new(Vec3::splat(6.0))),
+            ),
+            Disk::new(
+                Vec3::new(5.0, 4.0, -1.0),
+                Vec3::new(-1.0, -0.6, 0.2),
+                0.8,
+                DiffuseLight::one_sided(Solid::new(Vec3::new(8.0, 5.0, 2.0))),
+            ),
+        )
+    }
+}
+
+impl Demo for PlanarShapes {
+    type DemoT = BvhNode<Arc<dyn ParallelHit>>;
+
+    fn name(&self) -> &'static str {
+        "planar_shapes"
+    }
+
+    fn background(&self, _camera: &Camera) -> Box<dyn Background> {
+        Box::new(Constant::new(Vec3::splat(0.02)))
+    }
+
+    fn lights(&self) -> Vec<Arc<dyn ParallelSample>> {
+        let (panel, round) = Self::light_shapes();
+
+        vec![Arc::new(panel), Arc::new(round)]
+    }
+
+    fn world(&self) -> Self::DemoT {
+        let mut world: Vec<Arc<dyn ParallelHit>> = Vec::with_capacity(8);
+
+        let mut rng = rand::thread_rng();
+        let mut rng = SmallRng::from_rng(&mut rng).unwrap();
+
+        let white = Lambertian::new(Solid::new(Vec3::splat(0.73)));
+        let checker = Lambertian::new(
+            UvChecker::new(
+                Solid::new(Vec3::new(0.2, 0.3, 0.6)),
+                Solid::new(Vec3::splat(0.9)),
+            )
+            .with_repeat((8.0, 8.0)),
+        );
+
+        // Floor
+        world.push(Arc::new(Quad::new(
+            Vec3::new(-6.0, 0.0, 4.0),
+            Vec3::new(12.0, 0.0, 0.0),
+            Vec3::new(0.0, 0.0, -10.0),
+            white.clone(),
+        )));
+
+        // Back wall out of two triangles, The checker runs across both of them
+        let (a, b, c, d) = (
+            Vec3::new(-6.0, 0.0, -6.0),
+            Vec3::new(6.0, 0.0, -6.0),
+            Vec3::new(6.0, 8.0, -6.0),
+            Vec3::new(-6.0, 8.0, -6.0),
+        );
+        world.push(Arc::new(Triangle::new(a, b, c, checker.clone()).with_uvs(
+            (0.0, 0.0),
+            (1.0, 0.0),
+            (1.0, 1.0),
+        )));
+        world.push(Arc::new(Triangle::new(a, c, d, checker.clone()).with_uvs(
+            (0.0, 0.0),
+            (1.0, 1.0),
+            (0.0, 1.0),
+        )));
+
+        // Mirror in the corner, Turned towards the disk
+        world.push(Arc::new(Quad::new(
+            Vec3::new(-5.0, 0.0, -3.0),
+            Vec3::new(2.0, 0.0, -2.0),
+            Vec3::new(0.0, 4.0, -1.5),
+            Metal::with_fuzz(Vec3::splat(0.9), 0.02),
+        )));
+
+        // Checkered disk tilted up towards the camera
+        world.push(Arc::new(Disk::new(
+            Vec3::new(2.5, 1.6, -2.0),
+            Vec3::new(-0.3, 0.5, 1.0),
+            1.5,
+            checker,
+        )));
+
+        let (panel, round) = Self::light_shapes();
+        world.push(Arc::new(panel));
+        world.push(Arc::new(round));
+
+        BvhNode::new(&mut rng, &mut world, 0.0, 1.0)
+    }
+
+    fn camera(&self, aspect_ratio: f64) -> Camera {
+        let lookfrom = Vec3::new(0.0, 3.5, 14.0);
+        let lookat = Vec3::new(0.0, 2.5, 0.0);
+        let aperture = 0.0;
+        let focus_distance = 14.0;
+        Camera::new(
+            lookfrom,
+            lookat,
+            Vec3::new(0.0, 1.0, 0.0),
+            40.0,
+            aspect_ratio,
+            aperture,
+            focus_distance,
+            0.0,
+            1.0,
+        )
+    }
+}
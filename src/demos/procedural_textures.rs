@@ -0,0 +1,152 @@
+use std::sync::Arc;
+
+use rand::{prelude::SmallRng, SeedableRng};
+
+use crate::{
+    background::VerticalGradient,
+    demos::{Demo, ParallelHit},
+    hitable::{shapes::Sphere, BvhNode},
+    materials::Lambertian,
+    texture::{
+        noise::{DomainWarp, Fbm, Perlin, Ridged, Simplex, Worley},
+        Clouds, ColorRamp, Granite, Marble, Wood,
+    },
+    types::Vec3,
+    Background, Camera,
+};
+
+pub struct ProceduralTextures {}
+
+impl Demo for ProceduralTextures {
+    type DemoT = BvhNode<Arc<dyn ParallelHit>>;
+
+    fn name(&self) -> &'static str {
+        "procedural_textures"
+    }
+
+    fn background(&self, _camera: &Camera) -> Box<dyn Background> {
+        Box::new(VerticalGradient::new(
+            Vec3::new(1.0, 1.0, 1.0),
+            Vec3::new(0.5, 0.7, 1.0),
+        ))
+    }
+
+    fn world(&self) -> Self::DemoT {
+        let mut world: Vec<Arc<dyn ParallelHit>> = Vec::with_capacity(5);
+
+        let mut rng = rand::thread_rng();
+        let mut rng = SmallRng::from_rng(&mut rng).unwrap();
+
+        // Slate with veins
+        world.push(Arc::new(Sphere::new(
+            Vec3::new(0.0, -1000.0, 0.0),
+            1000.0,
+            Lambertian::new(
+                Marble::new(
+                    Ridged::new(Simplex::new(&mut rng), 5),
+                    ColorRamp::new(vec![
+                        (0.0, Vec3::new(0.15, 0.16, 0.18)),
+                        (0.7, Vec3::new(0.3, 0.32, 0.35)),
+                        (1.0, Vec3::new(0.8, 0.8, 0.75)),
+                    ]),
+                )
+                .with_axis(Vec3::new(1.0, 0.0, 0.5))
+                .with_distortion(4.0)
+                .with_scale(0.5),
+            ),
+        )));
+
+        world.push(Arc::new(Sphere::new(
+            Vec3::new(-6.0, 1.5, 0.0),
+            1.5,
+            Lambertian::new(
+                Marble::new(
+                    Fbm::new(Perlin::new(&mut rng), 7),
+                    ColorRamp::new(vec![
+                        (0.0, Vec3::new(0.1, 0.1, 0.12)),
+                        (0.2, Vec3::new(0.5, 0.5, 0.55)),
+                        (1.0, Vec3::new(0.95, 0.95, 0.92)),
+                    ]),
+                )
+                .with_axis(Vec3::new(3.0, 2.0, 0.0))
+                .with_distortion(12.0)
+                .with_scale(1.5),
+            ),
+        )));
+
+        world.push(Arc::new(Sphere::new(
+            Vec3::new(-2.0, 1.5, 0.0),
+            1.5,
+            Lambertian::new(
+                Wood::new(
+                    Fbm::new(Simplex::new(&mut rng), 4),
+                    ColorRamp::new(vec![
+                        (0.0, Vec3::new(0.45, 0.25, 0.1)),
+                        (0.7, Vec3::new(0.6, 0.38, 0.18)),
+                        (1.0, Vec3::new(0.3, 0.15, 0.05)),
+                    ]),
+                )
+                .with_rings(5.0)
+                .with_distortion(0.4),
+            ),
+        )));
+
+        world.push(Arc::new(Sphere::new(
+            Vec3::new(2.0, 1.5, 0.0),
+            1.5,
+            Lambertian::new(
+                Granite::new(
+                    Worley::new(&mut rng),
+                    ColorRamp::new(vec![
+                        (0.0, Vec3::new(0.05, 0.05, 0.05)),
+                        (0.3, Vec3::new(0.6, 0.55, 0.5)),
+                        (0.8, Vec3::new(0.85, 0.6, 0.55)),
+                        (1.0, Vec3::new(0.9, 0.9, 0.9)),
+                    ]),
+                )
+                .with_scale(12.0),
+            ),
+        )));
+
+        world.push(Arc::new(Sphere::new(
+            Vec3::new(6.0, 1.5, 0.0),
+            1.5,
+            Lambertian::new(
+                Clouds::new(
+                    DomainWarp::new(
+                        Fbm::new(Simplex::new(&mut rng), 6).with_gain(0.55),
+                        Fbm::new(Perlin::new(&mut rng), 3),
+                        1.5,
+                    ),
+                    ColorRamp::new(vec![
+                        (0.0, Vec3::new(0.2, 0.4, 0.85)),
+                        (0.6, Vec3::new(0.85, 0.88, 0.92)),
+                        (1.0, Vec3::new(1.0, 1.0, 1.0)),
+                    ]),
+                )
+                .with_coverage(0.45)
+                .with_scale(1.2),
+            ),
+        )));
+
+        BvhNode::new(&mut rng, &mut world, 0.0, 1.0)
+    }
+
+    fn camera(&self, aspect_ratio: f64) -> Camera {
+        let lookfrom = Vec3::new(0.0, 5.0, 26.0);
+        let lookat = Vec3::new(0.0, 1.5, 0.0);
+        let aperture = 0.0;
+        let focus_distance = 26.0;
+        Camera::new(
+            lookfrom,
+            lookat,
+            Vec3::new(0.0, 1.0, 0.0),
+            30.0,
+            aspect_ratio,
+            aperture,
+            focus_distance,
+            0.0,
+            1.0,
+        )
+    }
+}
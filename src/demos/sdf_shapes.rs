@@ -0,0 +1,140 @@
+use std::sync::Arc;
+
+use rand::{prelude::SmallRng, SeedableRng};
+
+use crate::{
+    background::Sky,
+    demos::{Demo, ParallelHit},
+    hitable::{
+        sdf::{Ball, Mandelbulb, RoundedBox, Sdf, SdfShape},
+        shapes::Sphere,
+        BvhNode, Transform,
+    },
+    materials::{Dielectric, Lambertian, Metal},
+    texture::{Checker, Solid, UvChecker},
+    types::Vec3,
+    Aabb, Background, Camera,
+};
+
+pub struct SdfShapes {}
+
+impl Demo for SdfShapes {
+    type DemoT = BvhNode<Arc<dyn ParallelHit>>;
+
+    fn name(&self) -> &'static str {
+        "sdf_shapes"
+    }
+
+    fn background(&self, _camera: &Camera) -> Box<dyn Background> {
+        let sky = Sky::new(Vec3::new(1.0, 1.4, 1.0), 3.0, Vec3::splat(0.3));
+
+        Box::new(sky.environment_map(1024, 512))
+    }
+
+    fn world(&self) -> Self::DemoT {
+        let mut world: Vec<Arc<dyn ParallelHit>> = Vec::with_capacity(5);
+
+        let mut rng = rand::thread_rng();
+        let mut rng = SmallRng::from_rng(&mut rng).unwrap();
+
+        world.push(Arc::new(Sphere::new(
+            Vec3::new(0.0, -1000.0, 0.0),
+            1000.0,
+            Lambertian::new(
+                Checker::new(
+                    Solid::new(Vec3::new(0.2, 0.3, 0.1)),
+                    Solid::new(Vec3::splat(0.9)),
+                )
+                .with_frequency(2.0),
+            ),
+        )));
+
+        // Rounded box standing on one edge
+        world.push(Arc::new(
+            Transform::new(Arc::new(SdfShape::new(
+                RoundedBox::new(Vec3::new(0.8, 0.8, 0.8), 0.2),
+                Aabb::new(Vec3::splat(-0.8), Vec3::splat(0.8)),
+                Metal::with_fuzz(Vec3::new(0.9, 0.6, 0.3), 0.05),
+            )))
+            .with_rotation(Vec3::new(0.0, 0.0, 1.0), 45.0)
+            .with_rotation(Vec3::new(0.0, 1.0, 0.0), 30.0)
+            .with_translation(Vec3::new(-3.6, 1.0, 0.0)),
+        ));
+
+        // Mandelbulb, The checker shows off the triplanar UVs
+        world.push(Arc::new(
+            Transform::new(Arc::new(
+                SdfShape::new(
+                    Mandelbulb::new().with_iterations(8),
+                    Aabb::new(Vec3::splat(-1.25), Vec3::splat(1.25)),
+                    Lambertian::new(
+                        UvChecker::new(
+                            Solid::new(Vec3::new(0.1, 0.3, 0.7)),
+                            Solid::new(Vec3::splat(0.9)),
+                        )
+                        .with_repeat((2.0, 2.0)),
+                    ),
+                )
+                .with_max_steps(400)
+                .with_step_scale(0.8)
+                .with_uv_scale(2.0),
+            ))
+            .with_scale(Vec3::splat(1.3))
+            .with_translation(Vec3::new(-1.3, 1.5, 0.0)),
+        ));
+
+        // Drops melted together & rippled
+        let blob = Ball::new(0.7)
+            .smooth_union(Ball::new(0.45).offset(Vec3::new(0.8, 0.5, 0.0)), 0.4)
+            .smooth_union(Ball::new(0.35).offset(Vec3::new(-0.3, 0.8, 0.5)), 0.4)
+            .displace(|p: Vec3| 0.03 * (12.0 * p.x()).sin() * (12.0 * p.y()).sin());
+        world.push(Arc::new(
+            SdfShape::new(
+                blob.offset(Vec3::new(1.6, 0.75, 0.5)),
+                Aabb::new(Vec3::new(0.8, 0.0, -0.3), Vec3::new(2.95, 2.0, 1.3)),
+                Dielectric::new(1.5),
+            )
+            .with_step_scale(0.6),
+        ));
+
+        // Plain closure, A gyroid lattice cut down to a ball
+        let gyroid = |p: Vec3| {
+            let q = p * 6.0;
+            let lattice =
+                q.x().sin() * q.y().cos() + q.y().sin() * q.z().cos() + q.z().sin() * q.x().cos();
+
+            Ball::new(0.9).distance(p).max(lattice.abs() / 6.0 - 0.04)
+        };
+        world.push(Arc::new(
+            Transform::new(Arc::new(
+                SdfShape::new(
+                    gyroid,
+                    Aabb::new(Vec3::splat(-0.9), Vec3::splat(0.9)),
+                    Lambertian::new(Solid::new(Vec3::new(0.8, 0.2, 0.2))),
+                )
+                .with_step_scale(0.5),
+            ))
+            .with_translation(Vec3::new(3.7, 0.9, 0.0)),
+        ));
+
+        BvhNode::new(&mut rng, &mut world, 0.0, 1.0)
+    }
+
+    fn camera(&self, aspect_ratio: f64) -> Camera {
+        let lookfrom = Vec3::new(0.0, 3.5, 12.0);
+        let lookat = Vec3::new(0.0, 1.0, 0.0);
+        let aperture = 0.0;
+        let focus_distance = 12.0;
+        Camera::new(
+            lookfrom,
+            lookat,
+            Vec3::new(0.0, 1.0, 0.0),
+            45.0,
+            aspect_ratio,
+            aperture,
+            focus_distance,
+            0.0,
+            1.0,
+        )
+    }
+}
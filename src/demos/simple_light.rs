@@ -3,15 +3,16 @@ use std::sync::Arc;
 use rand::{prelude::SmallRng, SeedableRng};
 
 use crate::{
+    background::ScreenTexture,
     demos::{Demo, ParallelHit},
     hitable::{
         shapes::{RectBuilder, Sphere},
         BvhNode,
     },
     materials::{DiffuseLight, Lambertian, MaterialBuilder},
-    texture::{PerlinNoise, Solid},
+    texture::{Filter, ImageTexture, PerlinNoise, Solid},
     types::Vec3,
-    Camera,
+    Background, Camera,
 };
 
 pub struct SimpleLight {}
@@ -23,6 +24,13 @@ impl Demo for SimpleLight {
         "simple_light"
     }
 
+    fn background(&self, camera: &Camera) -> Box<dyn Background> {
+        match ImageTexture::from_filename("assets/night_sky.png") {
+            Ok(v) => Box::new(ScreenTexture::new(v.with_filter(Filter::Bilinear), camera)),
+            Err(e) => panic!("error in loading backdrop: {}", e),
+        }
+    }
+
     fn world(&self) -> Self::DemoT {
         let mut world: Vec<Arc<dyn ParallelHit>> = Vec::with_capacity(5);
         let mut rng = rand::thread_rng();
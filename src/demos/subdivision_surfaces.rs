@@ -0,0 +1,110 @@
+use std::sync::Arc;
+
+use rand::{prelude::SmallRng, SeedableRng};
+
+use crate::{
+    background::Sky,
+    demos::{Demo, ParallelHit},
+    hitable::{
+        mesh::{PolygonMesh, Scheme, TriangleMesh},
+        shapes::Sphere,
+        BvhNode, Hitable,
+    },
+    materials::{Dielectric, Lambertian, Metal},
+    texture::{Perlin, PerlinNoise, Solid},
+    types::Vec3,
+    Background, Camera,
+};
+
+pub struct SubdivisionSurfaces {}
+
+impl Demo for SubdivisionSurfaces {
+    type DemoT = BvhNode<Arc<dyn ParallelHit>>;
+
+    fn name(&self) -> &'static str {
+        "subdivision_surfaces"
+    }
+
+    fn background(&self, _camera: &Camera) -> Box<dyn Background> {
+        let sky = Sky::new(Vec3::new(1.0, 1.5, 1.0), 3.0, Vec3::splat(0.3));
+
+        Box::new(sky.environment_map(1024, 512))
+    }
+
+    fn world(&self) -> Self::DemoT {
+        let mut world: Vec<Arc<dyn ParallelHit>> = Vec::with_capacity(7);
+
+        let mut rng = rand::thread_rng();
+        let mut rng = SmallRng::from_rng(&mut rng).unwrap();
+
+        world.push(Arc::new(Sphere::new(
+            Vec3::new(0.0, -1000.0, 0.0),
+            1000.0,
+            Lambertian::new(Solid::new(Vec3::splat(0.5))),
+        )));
+
+        // Back row, The same cube with Catmull-Clark applied 1, 2 & 4 times
+        let cube = Lambertian::new(Solid::new(Vec3::new(0.7, 0.3, 0.2)));
+        for (i, &levels) in [1, 2, 4].iter().enumerate() {
+            let mesh = PolygonMesh::cube(0.8).subdivide(Scheme::CatmullClark, levels);
+
+            world.push(Arc::new(
+                TriangleMesh::new(mesh, cube.clone()).translate(Vec3::new(
+                    i as f64 * 2.2 - 2.2,
+                    0.5,
+                    -1.5,
+                )),
+            ));
+        }
+
+        // Front row, A tetrahedron with Loop applied once & 4 times
+        let tetrahedron = Metal::with_fuzz(Vec3::new(0.8, 0.7, 0.4), 0.1);
+        for (i, &levels) in [1, 4].iter().enumerate() {
+            let mesh = PolygonMesh::tetrahedron(1.8).subdivide(Scheme::Loop, levels);
+
+            world.push(Arc::new(
+                TriangleMesh::new(mesh, tetrahedron.clone()).translate(Vec3::new(
+                    i as f64 * 1.8 - 2.0,
+                    0.35,
+                    1.0,
+                )),
+            ));
+        }
+
+        // Finely subdivided cube pushed out along its normals by noise
+        let noise = PerlinNoise::from_perlin(Arc::new(Perlin::with_seed(11)), 6.0).with_depth(5);
+        let mesh = PolygonMesh::cube(0.6)
+            .subdivide(Scheme::CatmullClark, 6)
+            .displace(&noise, 0.2);
+
+        world.push(Arc::new(
+            TriangleMesh::new(mesh, Lambertian::new(noise)).translate(Vec3::new(1.8, 0.5, 1.0)),
+        ));
+
+        // Glass droplet, Loop subdivided tetrahedron in front of everything
+        let mesh = PolygonMesh::tetrahedron(1.6).subdivide(Scheme::Loop, 3);
+        world.push(Arc::new(
+            TriangleMesh::new(mesh, Dielectric::new(1.5)).translate(Vec3::new(0.9, 0.3, 2.6)),
+        ));
+
+        BvhNode::new(&mut rng, &mut world, 0.0, 1.0)
+    }
+
+    fn camera(&self, aspect_ratio: f64) -> Camera {
+        let lookfrom = Vec3::new(0.0, 3.0, 8.0);
+        let lookat = Vec3::new(0.0, 0.4, 0.0);
+        let aperture = 0.0;
+        let focus_distance = 8.0;
+        Camera::new(
+            lookfrom,
+            lookat,
+            Vec3::new(0.0, 1.0, 0.0),
+            40.0,
+            aspect_ratio,
+            aperture,
+            focus_distance,
+            0.0,
+            1.0,
+        )
+    }
+}
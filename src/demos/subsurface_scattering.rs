@@ -0,0 +1,106 @@
+use std::sync::Arc;
+
+use rand::{prelude::SmallRng, SeedableRng};
+
+use crate::{
+    background::Constant,
+    demos::{Demo, ParallelHit},
+    hitable::{
+        shapes::{Cuboid, RectBuilder, Sphere},
+        BvhNode, Hitable,
+    },
+    materials::{Dielectric, DiffuseLight, Lambertian, MaterialBuilder, Subsurface},
+    texture::Solid,
+    types::Vec3,
+    Background, Camera,
+};
+
+pub struct SubsurfaceScattering {}
+
+impl Demo for SubsurfaceScattering {
+    type DemoT = BvhNode<Arc<dyn ParallelHit>>;
+
+    fn name(&self) -> &'static str {
+        "subsurface_scattering"
+    }
+
+    fn background(&self, _camera: &Camera) -> Box<dyn Background> {
+        Box::new(Constant::new(Vec3::splat(0.02)))
+    }
+
+    fn world(&self) -> Self::DemoT {
+        let mut world: Vec<Arc<dyn ParallelHit>> = Vec::with_capacity(6);
+
+        let mut rng = rand::thread_rng();
+        let mut rng = SmallRng::from_rng(&mut rng).unwrap();
+
+        world.push(Arc::new(Sphere::new(
+            Vec3::new(0.0, -1000.0, 0.0),
+            1000.0,
+            Lambertian::new(Solid::new(Vec3::splat(0.5))),
+        )));
+
+        world.push(Arc::new(
+            RectBuilder
+                .x(-3.0..=3.0)
+                .z(-3.0..=3.0)
+                .y(8.0)
+                .material(DiffuseLight::one_sided(Solid::new(Vec3::splat(4.0))))
+                .flip_face(),
+        ));
+
+        // Jade
+        world.push(Arc::new(Subsurface::new(
+            Sphere::new(Vec3::new(-2.5, 1.0, 0.0), 1.0, Dielectric::new(1.6)),
+            1.6,
+            Vec3::new(0.85, 0.99, 0.9),
+            Vec3::new(0.6, 0.25, 0.4),
+        )));
+
+        // Marble
+        world.push(Arc::new(Subsurface::new(
+            Sphere::new(Vec3::new(2.5, 1.0, 0.0), 1.0, Dielectric::new(1.5)),
+            1.5,
+            Vec3::splat(0.999),
+            Vec3::new(0.2, 0.15, 0.1),
+        )));
+
+        // Candle and its flame
+        world.push(Arc::new(Subsurface::new(
+            Cuboid::new(
+                Vec3::new(-0.5, 0.0, -0.5),
+                Vec3::new(0.5, 2.5, 0.5),
+                Dielectric::new(1.45),
+            )
+            .rotate_y(30.0),
+            1.45,
+            Vec3::new(0.99, 0.97, 0.9),
+            Vec3::new(0.3, 0.15, 0.08),
+        )));
+        world.push(Arc::new(Sphere::new(
+            Vec3::new(0.0, 2.8, 0.0),
+            0.15,
+            DiffuseLight::new(Solid::new(Vec3::new(30.0, 16.0, 5.0))),
+        )));
+
+        BvhNode::new(&mut rng, &mut world, 0.0, 1.0)
+    }
+
+    fn camera(&self, aspect_ratio: f64) -> Camera {
+        let lookfrom = Vec3::new(0.0, 4.0, 12.0);
+        let lookat = Vec3::new(0.0, 1.0, 0.0);
+        let aperture = 0.0;
+        let focus_distance = 12.0;
+        Camera::new(
+            lookfrom,
+            lookat,
+            Vec3::new(0.0, 1.0, 0.0),
+            30.0,
+            aspect_ratio,
+            aperture,
+            focus_distance,
+            0.0,
+            1.0,
+        )
+    }
+}
@@ -0,0 +1,123 @@
+use std::sync::Arc;
+
+use rand::{prelude::SmallRng, SeedableRng};
+
+use crate::{
+    background::Sky,
+    demos::{Demo, ParallelHit},
+    hitable::{
+        shapes::{Heightfield, Quad},
+        BvhNode, Hitable,
+    },
+    materials::{Dielectric, Lambertian},
+    texture::{
+        noise::{Fbm, Perlin},
+        Clouds, ColorRamp, Filter, ImageTexture, Solid,
+    },
+    types::Vec3,
+    Background, Camera, Texture,
+};
+
+pub struct Terrain {}
+
+impl Demo for Terrain {
+    type DemoT = BvhNode<Arc<dyn ParallelHit>>;
+
+    fn name(&self) -> &'static str {
+        "terrain"
+    }
+
+    fn background(&self, _camera: &Camera) -> Box<dyn Background> {
+        let sky = Sky::new(Vec3::new(-1.0, 0.8, 0.6), 3.0, Vec3::splat(0.3));
+
+        Box::new(sky.environment_map(1024, 512))
+    }
+
+    fn world(&self) -> Self::DemoT {
+        let mut world: Vec<Arc<dyn ParallelHit>> = Vec::with_capacity(4);
+
+        let mut rng = rand::thread_rng();
+        let mut rng = SmallRng::from_rng(&mut rng).unwrap();
+
+        // Island of a million cells, Heights from noise that fades out towards the edges
+        let hills = Clouds::new(
+            Fbm::new(Perlin::with_seed(7), 7).with_gain(0.5),
+            ColorRamp::new(vec![(0.0, Vec3::splat(0.0)), (1.0, Vec3::splat(1.0))]),
+        )
+        .with_coverage(0.0)
+        .with_scale(0.5);
+
+        let n = 1024;
+        let mut heights = Vec::with_capacity(n * n);
+        for j in 0..n {
+            for i in 0..n {
+                let (x, z) = (i as f64 / (n - 1) as f64, j as f64 / (n - 1) as f64);
+                let p = Vec3::new(x * 10.0, 0.0, z * 10.0);
+
+                let falloff = 1.0 - ((x - 0.5).powi(2) + (z - 0.5).powi(2)).sqrt() * 2.0;
+                let height = hills.value(x, 1.0 - z, p).x() * falloff.max(0.0);
+
+                heights.push(height);
+            }
+        }
+
+        world.push(Arc::new(
+            Heightfield::new(
+                n,
+                n,
+                &heights,
+                Vec3::new(10.0, 5.0, 10.0),
+                Lambertian::new(Solid::new(Vec3::new(0.45, 0.55, 0.3))),
+            )
+            .translate(Vec3::new(-11.0, -0.6, -5.0)),
+        ));
+
+        world.push(Arc::new(Quad::new(
+            Vec3::new(-11.0, 0.7, -5.0),
+            Vec3::new(0.0, 0.0, 10.0),
+            Vec3::new(10.0, 0.0, 0.0),
+            Dielectric::new(1.33),
+        )));
+        world.push(Arc::new(Quad::new(
+            Vec3::new(-11.0, -0.6, -5.0),
+            Vec3::new(0.0, 0.0, 10.0),
+            Vec3::new(10.0, 0.0, 0.0),
+            Lambertian::new(Solid::new(Vec3::new(0.1, 0.25, 0.35))),
+        )));
+
+        // Relief map, The same image gives both the heights & the colors
+        let earth = match ImageTexture::from_filename("assets/earthmap.jpg") {
+            Ok(v) => v.with_filter(Filter::Bilinear),
+            Err(e) => panic!("error in creating image texture: {}", e),
+        };
+        let relief = match Heightfield::from_image(
+            "assets/earthmap.jpg",
+            Vec3::new(10.0, 0.25, 5.0),
+            Lambertian::new(earth),
+        ) {
+            Ok(v) => v,
+            Err(e) => panic!("error in creating heightfield: {}", e),
+        };
+        world.push(Arc::new(relief.translate(Vec3::new(1.0, 0.0, -2.5))));
+
+        BvhNode::new(&mut rng, &mut world, 0.0, 1.0)
+    }
+
+    fn camera(&self, aspect_ratio: f64) -> Camera {
+        let lookfrom = Vec3::new(0.0, 15.0, 21.0);
+        let lookat = Vec3::new(0.0, 0.0, 0.0);
+        let aperture = 0.0;
+        let focus_distance = 26.0;
+        Camera::new(
+            lookfrom,
+            lookat,
+            Vec3::new(0.0, 1.0, 0.0),
+            45.0,
+            aspect_ratio,
+            aperture,
+            focus_distance,
+            0.0,
+            1.0,
+        )
+    }
+}
@@ -0,0 +1,170 @@
+use std::sync::Arc;
+
+use rand::{prelude::SmallRng, SeedableRng};
+
+use crate::{
+    background::Constant,
+    demos::{Demo, ParallelHit},
+    hitable::{
+        shapes::{Cuboid, Sphere},
+        BvhNode, Hitable,
+    },
+    materials::Lambertian,
+    texture::{
+        nodes::{Add, Invert, Mix, Multiply, PointTransform, Ramp, Triplanar, UvTransform},
+        noise::{Fbm, Perlin, Simplex, Worley},
+        Checker, Clouds, ColorRamp, Filter, Granite, ImageTexture, Marble, ParallelTexture,
+        PerlinNoise, Solid, Wood, Wrap,
+    },
+    types::Vec3,
+    Background, Camera,
+};
+
+pub struct TextureNodes {}
+
+impl Demo for TextureNodes {
+    type DemoT = BvhNode<Arc<dyn ParallelHit>>;
+
+    fn name(&self) -> &'static str {
+        "texture_nodes"
+    }
+
+    fn background(&self, _camera: &Camera) -> Box<dyn Background> {
+        Box::new(Constant::new(Vec3::new(0.7, 0.8, 1.0)))
+    }
+
+    fn world(&self) -> Self::DemoT {
+        let mut world: Vec<Arc<dyn ParallelHit>> = Vec::with_capacity(5);
+
+        let mut rng = rand::thread_rng();
+        let mut rng = SmallRng::from_rng(&mut rng).unwrap();
+
+        let earth: Arc<dyn ParallelTexture> =
+            match ImageTexture::from_filename("assets/earthmap.jpg") {
+                Ok(v) => Arc::new(v.with_filter(Filter::Bilinear).with_wrap(Wrap::Repeat)),
+                Err(e) => panic!("error in creating image texture: {}", e),
+            };
+
+        // Children of a checker can be of different types now
+        let granite: Arc<dyn ParallelTexture> = Arc::new(
+            Granite::new(
+                Worley::new(&mut rng),
+                ColorRamp::new(vec![
+                    (0.0, Vec3::new(0.2, 0.2, 0.2)),
+                    (1.0, Vec3::new(0.9, 0.9, 0.9)),
+                ]),
+            )
+            .with_scale(8.0),
+        );
+        let checker: Arc<dyn ParallelTexture> = Arc::new(Checker::new(
+            Arc::new(Solid::new(Vec3::new(0.9, 0.9, 0.9))) as Arc<dyn ParallelTexture>,
+            granite,
+        ));
+
+        world.push(Arc::new(Sphere::new(
+            Vec3::new(0.0, -1000.0, 0.0),
+            1000.0,
+            // Dark squares are lifted a little so that the grains stay visible
+            Lambertian::new(Add::new(
+                Arc::new(Multiply::new(
+                    checker,
+                    Arc::new(Solid::new(Vec3::new(0.8, 0.9, 0.7))),
+                )),
+                Arc::new(Solid::new(Vec3::splat(0.05))),
+            )),
+        )));
+
+        // Marble veins fading into wood where the clouds are
+        let marble = Marble::new(
+            Fbm::new(Perlin::new(&mut rng), 6),
+            ColorRamp::new(vec![
+                (0.0, Vec3::new(0.2, 0.2, 0.25)),
+                (1.0, Vec3::new(0.95, 0.95, 0.95)),
+            ]),
+        )
+        .with_axis(Vec3::new(4.0, 0.0, 2.0));
+        let wood = Wood::new(
+            Fbm::new(Simplex::new(&mut rng), 4),
+            ColorRamp::new(vec![
+                (0.0, Vec3::new(0.45, 0.25, 0.1)),
+                (1.0, Vec3::new(0.3, 0.15, 0.05)),
+            ]),
+        )
+        .with_rings(6.0);
+        let mask = Clouds::new(
+            Fbm::new(Perlin::new(&mut rng), 4),
+            ColorRamp::new(vec![(0.0, Vec3::splat(0.0)), (1.0, Vec3::splat(1.0))]),
+        )
+        .with_coverage(0.1)
+        .with_scale(1.5);
+
+        world.push(Arc::new(Sphere::new(
+            Vec3::new(-6.0, 1.5, 0.0),
+            1.5,
+            Lambertian::new(Mix::new(Arc::new(marble), Arc::new(wood), Arc::new(mask))),
+        )));
+
+        // Tilted & stretched noise colored like lava
+        world.push(Arc::new(Sphere::new(
+            Vec3::new(-2.0, 1.5, 0.0),
+            1.5,
+            Lambertian::new(Ramp::new(
+                Arc::new(
+                    PointTransform::new(Arc::new(Invert::new(Arc::new(PerlinNoise::with_scale(
+                        &mut rng, 2.0,
+                    )))))
+                    .with_rotation(Vec3::new(1.0, 0.0, 1.0), 45.0)
+                    .with_scale(Vec3::new(1.0, 3.0, 1.0)),
+                ),
+                ColorRamp::new(vec![
+                    (0.0, Vec3::new(0.05, 0.0, 0.0)),
+                    (0.5, Vec3::new(0.8, 0.15, 0.0)),
+                    (1.0, Vec3::new(1.0, 0.85, 0.3)),
+                ]),
+            )),
+        )));
+
+        // Earth tiled twice & turned upside down
+        world.push(Arc::new(Sphere::new(
+            Vec3::new(2.0, 1.5, 0.0),
+            1.5,
+            Lambertian::new(
+                UvTransform::new(earth.clone())
+                    .with_rotation(180.0)
+                    .with_scale((2.0, 1.0))
+                    .with_offset((0.25, 0.0)),
+            ),
+        )));
+
+        // Boxes have no texture coordinates that would make the image fit on them
+        world.push(Arc::new(
+            Cuboid::new(
+                Vec3::new(-1.2, 0.0, -1.2),
+                Vec3::new(1.2, 2.4, 1.2),
+                Lambertian::new(Triplanar::new(earth).with_scale(0.4)),
+            )
+            .rotate_y(30.0)
+            .translate(Vec3::new(6.0, 0.0, 0.0)),
+        ));
+
+        BvhNode::new(&mut rng, &mut world, 0.0, 1.0)
+    }
+
+    fn camera(&self, aspect_ratio: f64) -> Camera {
+        let lookfrom = Vec3::new(0.0, 5.0, 26.0);
+        let lookat = Vec3::new(0.0, 1.5, 0.0);
+        let aperture = 0.0;
+        let focus_distance = 26.0;
+        Camera::new(
+            lookfrom,
+            lookat,
+            Vec3::new(0.0, 1.0, 0.0),
+            30.0,
+            aspect_ratio,
+            aperture,
+            focus_distance,
+            0.0,
+            1.0,
+        )
+    }
+}
@@ -0,0 +1,90 @@
+use std::sync::Arc;
+
+use rand::{prelude::SmallRng, SeedableRng};
+
+use crate::{
+    background::Constant,
+    demos::{Demo, ParallelHit},
+    hitable::{shapes::Sphere, BvhNode},
+    materials::{Lambertian, Substrate, ThinFilm},
+    texture::{Checker, PerlinNoise, Solid},
+    types::Vec3,
+    Background, Camera,
+};
+
+pub struct ThinFilmInterference {}
+
+impl Demo for ThinFilmInterference {
+    type DemoT = BvhNode<Arc<dyn ParallelHit>>;
+
+    fn name(&self) -> &'static str {
+        "thin_film_interference"
+    }
+
+    fn background(&self, _camera: &Camera) -> Box<dyn Background> {
+        Box::new(Constant::new(Vec3::new(0.7, 0.8, 1.0)))
+    }
+
+    fn world(&self) -> Self::DemoT {
+        let mut world: Vec<Arc<dyn ParallelHit>> = Vec::with_capacity(4);
+
+        let mut rng = rand::thread_rng();
+        let mut rng = SmallRng::from_rng(&mut rng).unwrap();
+
+        world.push(Arc::new(Sphere::new(
+            Vec3::new(0.0, -1000.0, 0.0),
+            1000.0,
+            Lambertian::new(Checker::new(
+                Solid::new(Vec3::new(0.2, 0.3, 0.1)),
+                Solid::new(Vec3::new(0.9, 0.9, 0.9)),
+            )),
+        )));
+
+        // Soap bubble, The film drains and gets thinner in some places
+        world.push(Arc::new(Sphere::new(
+            Vec3::new(-2.2, 2.0, 0.0),
+            2.0,
+            ThinFilm::with_thickness_texture(
+                PerlinNoise::with_scale(&mut rng, 2.0),
+                200.0,
+                900.0,
+                1.33,
+                Substrate::Dielectric(1.0),
+            ),
+        )));
+
+        // Anodised titanium
+        world.push(Arc::new(Sphere::new(
+            Vec3::new(2.2, 2.0, 0.0),
+            2.0,
+            ThinFilm::new(
+                250.0,
+                2.3,
+                Substrate::Conductor {
+                    eta: Vec3::new(2.74, 2.54, 2.27),
+                    k: Vec3::new(3.79, 3.43, 3.04),
+                },
+            ),
+        )));
+
+        BvhNode::new(&mut rng, &mut world, 0.0, 1.0)
+    }
+
+    fn camera(&self, aspect_ratio: f64) -> Camera {
+        let lookfrom = Vec3::new(0.0, 4.0, 16.0);
+        let lookat = Vec3::new(0.0, 2.0, 0.0);
+        let aperture = 0.0;
+        let focus_distance = 16.0;
+        Camera::new(
+            lookfrom,
+            lookat,
+            Vec3::new(0.0, 1.0, 0.0),
+            30.0,
+            aspect_ratio,
+            aperture,
+            focus_distance,
+            0.0,
+            1.0,
+        )
+    }
+}
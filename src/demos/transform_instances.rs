@@ -0,0 +1,117 @@
+use std::sync::Arc;
+
+use rand::{prelude::SmallRng, Rng, SeedableRng};
+
+use crate::{
+    background::Sky,
+    demos::{Demo, ParallelHit},
+    hitable::{
+        shapes::{Cuboid, Sphere},
+        BvhNode, Transform,
+    },
+    materials::{Dielectric, Lambertian, Metal},
+    texture::{Checker, Solid, UvChecker},
+    types::Vec3,
+    Background, Camera,
+};
+
+pub struct TransformInstances {}
+
+impl Demo for TransformInstances {
+    type DemoT = BvhNode<Arc<dyn ParallelHit>>;
+
+    fn name(&self) -> &'static str {
+        "transform_instances"
+    }
+
+    fn background(&self, _camera: &Camera) -> Box<dyn Background> {
+        let sky = Sky::new(Vec3::new(1.0, 1.2, 0.4), 3.0, Vec3::splat(0.3));
+
+        Box::new(sky.environment_map(1024, 512))
+    }
+
+    fn world(&self) -> Self::DemoT {
+        let mut world: Vec<Arc<dyn ParallelHit>> = Vec::with_capacity(1700);
+
+        let mut rng = rand::thread_rng();
+        let mut rng = SmallRng::from_rng(&mut rng).unwrap();
+
+        world.push(Arc::new(Sphere::new(
+            Vec3::new(0.0, -1000.0, 0.0),
+            1000.0,
+            Lambertian::new(
+                Checker::new(
+                    Solid::new(Vec3::new(0.2, 0.3, 0.1)),
+                    Solid::new(Vec3::splat(0.9)),
+                )
+                .with_frequency(2.0),
+            ),
+        )));
+
+        // Only these three are ever built, Everything else is an instance of one of them
+        let shapes: [Arc<dyn ParallelHit>; 3] = [
+            Arc::new(Cuboid::new(
+                Vec3::splat(-0.5),
+                Vec3::splat(0.5),
+                Metal::with_fuzz(Vec3::new(0.8, 0.6, 0.4), 0.2),
+            )),
+            Arc::new(Sphere::new(
+                Vec3::splat(0.0),
+                0.5,
+                Lambertian::new(UvChecker::new(
+                    Solid::new(Vec3::new(0.7, 0.1, 0.1)),
+                    Solid::new(Vec3::splat(0.9)),
+                )),
+            )),
+            Arc::new(Sphere::new(Vec3::splat(0.0), 0.5, Dielectric::new(1.5))),
+        ];
+
+        for i in -20..20 {
+            for j in -20..20 {
+                let shape = shapes[rng.gen_range(0..shapes.len())].clone();
+                let scale = Vec3::random_in_range(&mut rng, 0.2..=0.6);
+                let axis = Vec3::random_in_range(&mut rng, -1.0..=1.0);
+                let position = Vec3::new(
+                    i as f64 + rng.gen_range(0.0..0.5),
+                    scale.y() / 2.0,
+                    j as f64 + rng.gen_range(0.0..0.5),
+                );
+
+                world.push(Arc::new(
+                    Transform::new(shape)
+                        .with_scale(scale)
+                        .with_rotation(axis, rng.gen_range(0.0..360.0))
+                        .with_translation(position),
+                ));
+            }
+        }
+
+        // A big sheared & squashed copy of the checkered sphere in the middle
+        world.push(Arc::new(
+            Transform::new(shapes[1].clone())
+                .with_scale(Vec3::new(3.0, 1.5, 3.0))
+                .with_shear(0.6, 0.0, 0.0, 0.0, 0.0, 0.3)
+                .with_translation(Vec3::new(0.0, 1.5, 0.0)),
+        ));
+
+        BvhNode::new(&mut rng, &mut world, 0.0, 1.0)
+    }
+
+    fn camera(&self, aspect_ratio: f64) -> Camera {
+        let lookfrom = Vec3::new(0.0, 7.0, 16.0);
+        let lookat = Vec3::new(0.0, 0.5, 0.0);
+        let aperture = 0.0;
+        let focus_distance = 16.0;
+        Camera::new(
+            lookfrom,
+            lookat,
+            Vec3::new(0.0, 1.0, 0.0),
+            35.0,
+            aspect_ratio,
+            aperture,
+            focus_distance,
+            0.0,
+            1.0,
+        )
+    }
+}
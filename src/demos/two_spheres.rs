@@ -3,12 +3,13 @@ use std::sync::Arc;
 use rand::{prelude::SmallRng, SeedableRng};
 
 use crate::{
+    background::Sky,
     demos::{Demo, ParallelHit},
     hitable::{shapes::Sphere, BvhNode},
     materials::Lambertian,
     texture::{Checker, Solid},
     types::Vec3,
-    Camera,
+    Background, Camera,
 };
 
 pub struct TwoSpheres {}
@@ -20,8 +21,10 @@ impl Demo for TwoSpheres {
         "two_checkered_sphere"
     }
 
-    fn get_background(&self) -> Vec3 {
-        Vec3::new(0.7, 0.8, 1.0)
+    fn background(&self, _camera: &Camera) -> Box<dyn Background> {
+        let sky = Sky::new(Vec3::new(1.0, 1.2, 0.4), 3.0, Vec3::splat(0.3));
+
+        Box::new(sky.environment_map(1024, 512))
     }
 
     fn world(&self) -> Self::DemoT {
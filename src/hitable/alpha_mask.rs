@@ -0,0 +1,53 @@
+use crate::{
+    hitable::{HitRecord, Hitable},
+    types::Ray,
+    Aabb, Texture,
+};
+
+/// Cuts holes in the wrapped object wherever the opacity of the mask texture
+/// falls below the cutoff. Rays that land on a transparent texel carry on
+/// and can hit the parts of the same object that are further away.
+pub struct AlphaMask<H, T> {
+    object: H,
+    mask: T,
+    cutoff: f64,
+}
+
+impl<H: Hitable, T: Texture> AlphaMask<H, T> {
+    pub fn new(object: H, mask: T) -> Self {
+        Self {
+            object,
+            mask,
+            cutoff: 0.5,
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn with_cutoff(object: H, mask: T, cutoff: f64) -> Self {
+        Self {
+            object,
+            mask,
+            cutoff,
+        }
+    }
+}
+
+impl<H: Hitable, T: Texture> Hitable for AlphaMask<H, T> {
+    fn hit(&self, ray: &Ray, t_min: f64, t_max: f64) -> Option<HitRecord> {
+        let mut t_min = t_min;
+
+        loop {
+            let hit = self.object.hit(ray, t_min, t_max)?;
+
+            if self.mask.alpha_at(&hit) >= self.cutoff {
+                return Some(hit);
+            }
+
+            t_min = hit.t + 0.0001;
+        }
+    }
+
+    fn bounding_box(&self, t0: f64, t1: f64) -> Option<Aabb> {
+        self.object.bounding_box(t0, t1)
+    }
+}
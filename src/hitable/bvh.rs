@@ -1,6 +1,6 @@
 use std::cmp::Ordering;
 
-use rand::{prelude::SliceRandom, Rng};
+use rand::Rng;
 
 use crate::{
     hitable::{HitRecord, Hitable},
@@ -16,13 +16,8 @@ pub struct BvhNode<T: Hitable> {
 
 impl<T: Hitable + Clone> BvhNode<T> {
     pub fn new<R: Rng + ?Sized>(rng: &mut R, objects: &mut [T], t0: f64, t1: f64) -> Self {
-        let comparator = [
-            Self::box_x_compare,
-            Self::box_y_compare,
-            Self::box_z_compare,
-        ]
-        .choose(rng)
-        .unwrap();
+        let axis = rng.gen_range(0..3);
+        let comparator = |a: &T, b: &T| Self::box_compare(a, b, axis, t0, t1);
 
         let (left, right) = match objects.len() {
             1 => (
@@ -64,31 +59,21 @@ impl<T: Hitable + Clone> BvhNode<T> {
         }
     }
 
-    fn box_x_compare(obj1: &T, obj2: &T) -> Ordering {
-        if let (Some(bbox_a), Some(bbox_b)) =
-            (obj1.bounding_box(0.0, 0.0), obj2.bounding_box(0.0, 0.0))
+    // Compares the boxes over the whole time range the tree is built for, Objects that move
+    // are sorted by where they are on average instead of where they start
+    fn box_compare(obj1: &T, obj2: &T, axis: usize, t0: f64, t1: f64) -> Ordering {
+        if let (Some(bbox_a), Some(bbox_b)) = (obj1.bounding_box(t0, t1), obj2.bounding_box(t0, t1))
         {
-            return bbox_a.min.x().partial_cmp(&bbox_b.min.x()).unwrap();
-        }
-
-        panic!("No bounding box for this BVH Node!!")
-    }
-
-    fn box_y_compare(obj1: &T, obj2: &T) -> Ordering {
-        if let (Some(bbox_a), Some(bbox_b)) =
-            (obj1.bounding_box(0.0, 0.0), obj2.bounding_box(0.0, 0.0))
-        {
-            return bbox_a.min.y().partial_cmp(&bbox_b.min.y()).unwrap();
-        }
+            let center_a = (bbox_a.min + bbox_a.max) * 0.5;
+            let center_b = (bbox_b.min + bbox_b.max) * 0.5;
 
-        panic!("No bounding box for this BVH Node!!")
-    }
+            let (a, b) = match axis {
+                0 => (center_a.x(), center_b.x()),
+                1 => (center_a.y(), center_b.y()),
+                _ => (center_a.z(), center_b.z()),
+            };
 
-    fn box_z_compare(obj1: &T, obj2: &T) -> Ordering {
-        if let (Some(bbox_a), Some(bbox_b)) =
-            (obj1.bounding_box(0.0, 0.0), obj2.bounding_box(0.0, 0.0))
-        {
-            return bbox_a.min.z().partial_cmp(&bbox_b.min.z()).unwrap();
+            return a.partial_cmp(&b).unwrap();
         }
 
         panic!("No bounding box for this BVH Node!!")
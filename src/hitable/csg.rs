@@ -0,0 +1,148 @@
+use crate::{
+    hitable::{HitRecord, Hitable},
+    types::{Ray, Vec3},
+    Aabb,
+};
+
+#[derive(Debug, Copy, Clone)]
+pub enum Operation {
+    Union,
+    Intersection,
+    /// The first object minus the second one
+    Difference,
+}
+
+impl Operation {
+    fn inside(&self, in_a: bool, in_b: bool) -> bool {
+        match self {
+            Operation::Union => in_a || in_b,
+            Operation::Intersection => in_a && in_b,
+            Operation::Difference => in_a && !in_b,
+        }
+    }
+}
+
+/// Constructive solid geometry, Boolean combination of two closed objects
+///
+/// Walks along all the surface crossings of both objects in order & keeps the ones where
+/// the ray goes in or out of the combined solid. Each surface keeps the material of the
+/// object it came from, So the walls of a hole carved with `Difference` show the material
+/// of the object that was subtracted
+pub struct Csg<A, B> {
+    a: A,
+    b: B,
+    operation: Operation,
+}
+
+impl<A: Hitable, B: Hitable> Csg<A, B> {
+    pub fn new(a: A, b: B, operation: Operation) -> Self {
+        Self { a, b, operation }
+    }
+}
+
+impl<A: Hitable, B: Hitable> Hitable for Csg<A, B> {
+    fn hit(&self, ray: &Ray, t_min: f64, t_max: f64) -> Option<HitRecord> {
+        self.hit_all(ray, t_min, t_max).into_iter().next()
+    }
+
+    fn bounding_box(&self, t0: f64, t1: f64) -> Option<Aabb> {
+        let a = self.a.bounding_box(t0, t1);
+        let b = self.b.bounding_box(t0, t1);
+
+        match self.operation {
+            Operation::Union => Some(Aabb::surrounding_box(a?, b?)),
+            Operation::Intersection => {
+                let (a, b) = (a?, b?);
+                let min = Vec3::max(a.min, b.min);
+
+                // Boxes don't overlap, Keeps the box valid even though nothing is inside it
+                Some(Aabb::new(min, Vec3::max(min, Vec3::min(a.max, b.max))))
+            }
+            Operation::Difference => a,
+        }
+    }
+
+    fn hit_all(&self, ray: &Ray, t_min: f64, t_max: f64) -> Vec<HitRecord> {
+        let (mut in_a, hits_a) = self.a.crossings(ray, t_min, t_max);
+        let (mut in_b, hits_b) = self.b.crossings(ray, t_min, t_max);
+
+        let mut merged = hits_a
+            .into_iter()
+            .map(|hit| (hit, true))
+            .chain(hits_b.into_iter().map(|hit| (hit, false)))
+            .collect::<Vec<_>>();
+        merged.sort_by(|(x, _), (y, _)| x.t.partial_cmp(&y.t).unwrap());
+
+        let mut inside = self.operation.inside(in_a, in_b);
+        let mut hits = vec![];
+
+        for (mut hit, from_a) in merged {
+            if from_a {
+                in_a = hit.front_face;
+            } else {
+                in_b = hit.front_face;
+            }
+
+            let now_inside = self.operation.inside(in_a, in_b);
+            if now_inside != inside {
+                // Normal already faces against the ray, Only whether this is the way in or
+                // out of the combined solid can differ from the object, e.g. leaving the
+                // subtracted object is entering the result
+                hit.front_face = now_inside;
+                hits.push(hit);
+                inside = now_inside;
+            }
+        }
+
+        hits
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        hitable::{
+            shapes::Sphere,
+            tests::{assert_intervals, gray},
+            Hitable,
+        },
+        types::{Ray, Vec3},
+    };
+
+    // Compares t & front_face of every crossing
+    fn assert_crossings(object: &impl Hitable, ray: &Ray, t_max: f64, expected: &[(f64, bool)]) {
+        let hits = object.hit_all(ray, 0.001, t_max);
+        assert_eq!(hits.len(), expected.len());
+
+        for (hit, &(t, front_face)) in hits.iter().zip(expected.iter()) {
+            assert!((hit.t - t).abs() < 1e-9, "{} != {}", hit.t, t);
+            assert_eq!(hit.front_face, front_face);
+        }
+    }
+
+    #[test]
+    fn sphere_csg_crossings() {
+        let a = || Sphere::new(Vec3::splat(0.0), 1.0, gray());
+        let b = || Sphere::new(Vec3::new(1.0, 0.0, 0.0), 1.0, gray());
+        let direction = Vec3::new(1.0, 0.0, 0.0);
+        let ray = Ray::new(Vec3::new(-5.0, 0.0, 0.0), direction, 0.0);
+
+        // a spans t = 4..6 & b spans t = 5..7
+        let difference = a().subtract(b());
+        assert_crossings(&difference, &ray, f64::MAX, &[(4.0, true), (5.0, false)]);
+        assert_intervals(difference.intervals(&ray, 0.001, f64::MAX), &[(4.0, 5.0)]);
+
+        let intersection = a().intersect(b());
+        assert_crossings(&intersection, &ray, f64::MAX, &[(5.0, true), (6.0, false)]);
+        assert_intervals(intersection.intervals(&ray, 0.001, f64::MAX), &[(5.0, 6.0)]);
+
+        // Starts inside of the difference & only leaves it after t_max
+        let inside = Ray::new(Vec3::new(-0.5, 0.0, 0.0), direction, 0.0);
+        assert_crossings(&difference, &inside, 0.3, &[]);
+        assert_intervals(difference.intervals(&inside, 0.001, 0.3), &[(0.001, 0.3)]);
+        assert_intervals(
+            difference.intervals(&inside, 0.001, f64::MAX),
+            &[(0.001, 0.5)],
+        );
+    }
+}
@@ -0,0 +1,31 @@
+use crate::{
+    hitable::{HitRecord, Hitable},
+    types::Ray,
+    Aabb,
+};
+
+/// Swaps the front & back face of the wrapped object.
+/// Useful with one sided lights whose natural normal points the wrong way
+/// like the axis aligned rectangles on the ceiling of a cornell box
+pub struct FlipFace<T> {
+    object: T,
+}
+
+impl<T> FlipFace<T> {
+    pub const fn new(object: T) -> Self {
+        Self { object }
+    }
+}
+
+impl<T: Hitable> Hitable for FlipFace<T> {
+    fn hit(&self, ray: &Ray, t_min: f64, t_max: f64) -> Option<HitRecord> {
+        let mut hit = self.object.hit(ray, t_min, t_max)?;
+        hit.front_face = !hit.front_face;
+
+        Some(hit)
+    }
+
+    fn bounding_box(&self, t0: f64, t1: f64) -> Option<Aabb> {
+        self.object.bounding_box(t0, t1)
+    }
+}
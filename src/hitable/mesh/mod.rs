@@ -0,0 +1,150 @@
+//! Polygon meshes that get refined & displaced as plain data, Then turned into a
+//! `TriangleMesh` that can be rendered
+
+mod subdivision;
+mod triangle_mesh;
+
+pub use triangle_mesh::TriangleMesh;
+
+use crate::{texture::nodes::luminance, types::Vec3, Texture};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Scheme {
+    /// Splits every triangle into 4, Polygons are split into triangles first
+    Loop,
+    /// Splits every polygon with n sides into n quads
+    CatmullClark,
+}
+
+/// Vertices & the polygons between them, Polygons list their vertices counter clockwise
+/// when looked at from the front
+#[derive(Debug, Clone)]
+pub struct PolygonMesh {
+    positions: Vec<Vec3>,
+    uvs: Vec<(f64, f64)>,
+    faces: Vec<Vec<usize>>,
+}
+
+impl PolygonMesh {
+    /// Texture coordinates are (0, 0) everywhere until set with `with_uvs`
+    pub fn new(positions: Vec<Vec3>, faces: Vec<Vec<usize>>) -> Self {
+        assert!(
+            faces.iter().flatten().all(|&i| i < positions.len()),
+            "face refers to a vertex that doesn't exist"
+        );
+        assert!(
+            faces.iter().all(|face| face.len() >= 3),
+            "faces need at least 3 vertices"
+        );
+
+        Self {
+            uvs: vec![(0.0, 0.0); positions.len()],
+            positions,
+            faces,
+        }
+    }
+
+    /// One (u, v) per vertex
+    #[allow(dead_code)]
+    pub fn with_uvs(mut self, uvs: Vec<(f64, f64)>) -> Self {
+        assert_eq!(uvs.len(), self.positions.len());
+
+        self.uvs = uvs;
+        self
+    }
+
+    /// Cube centered at the origin made of 6 quads
+    pub fn cube(half_size: f64) -> Self {
+        let positions = (0..8)
+            .map(|i| {
+                let sign = |bit: usize| if i & bit == 0 { -half_size } else { half_size };
+                Vec3::new(sign(1), sign(2), sign(4))
+            })
+            .collect();
+
+        let faces = vec![
+            vec![0, 4, 6, 2],
+            vec![1, 3, 7, 5],
+            vec![0, 1, 5, 4],
+            vec![2, 6, 7, 3],
+            vec![0, 2, 3, 1],
+            vec![4, 5, 7, 6],
+        ];
+
+        Self::new(positions, faces)
+    }
+
+    /// Regular tetrahedron with its corners on a sphere of radius around the origin
+    pub fn tetrahedron(radius: f64) -> Self {
+        let s = radius / 3.0f64.sqrt();
+        let positions = vec![
+            Vec3::new(s, s, s),
+            Vec3::new(s, -s, -s),
+            Vec3::new(-s, s, -s),
+            Vec3::new(-s, -s, s),
+        ];
+
+        let faces = vec![vec![0, 2, 3], vec![0, 3, 1], vec![0, 1, 2], vec![1, 3, 2]];
+
+        Self::new(positions, faces)
+    }
+
+    /// Refines the mesh levels times. The result gets closer to a smooth limit surface
+    /// with every level while the number of faces goes up ~4 times
+    pub fn subdivide(self, scheme: Scheme, levels: u32) -> Self {
+        (0..levels).fold(self, |mesh, _| match scheme {
+            Scheme::Loop => subdivision::loop_step(&mesh.triangulate()),
+            Scheme::CatmullClark => subdivision::catmull_clark_step(&mesh),
+        })
+    }
+
+    /// Moves every vertex along its normal by scale times the brightness of the texture
+    /// at the vertex. Works best after subdividing, Since there's nothing in between
+    /// the vertices to displace
+    pub fn displace(mut self, texture: &dyn Texture, scale: f64) -> Self {
+        let normals = self.vertex_normals();
+
+        for ((p, &(u, v)), normal) in self.positions.iter_mut().zip(&self.uvs).zip(normals) {
+            *p += normal * (luminance(texture.value(u, v, *p)) * scale);
+        }
+
+        self
+    }
+
+    /// Fans every polygon out from its first vertex
+    pub fn triangulate(&self) -> Self {
+        let faces = self
+            .faces
+            .iter()
+            .flat_map(|face| (1..face.len() - 1).map(move |i| vec![face[0], face[i], face[i + 1]]))
+            .collect();
+
+        Self {
+            positions: self.positions.clone(),
+            uvs: self.uvs.clone(),
+            faces,
+        }
+    }
+
+    /// Average of the normals of the faces around each vertex, Weighted by their area
+    fn vertex_normals(&self) -> Vec<Vec3> {
+        let mut normals = vec![Vec3::splat(0.0); self.positions.len()];
+
+        for face in &self.faces {
+            let origin = self.positions[face[0]];
+            let normal = (1..face.len() - 1).fold(Vec3::splat(0.0), |acc, i| {
+                let (a, b) = (self.positions[face[i]], self.positions[face[i + 1]]);
+                acc + (a - origin).cross(&(b - origin))
+            });
+
+            for &i in face {
+                normals[i] += normal;
+            }
+        }
+
+        normals
+            .into_iter()
+            .map(|n| if n.length() > 0.0 { n.unit_vector() } else { n })
+            .collect()
+    }
+}
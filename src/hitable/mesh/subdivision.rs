@@ -0,0 +1,265 @@
+use std::collections::HashMap;
+
+use crate::{hitable::mesh::PolygonMesh, types::Vec3};
+
+/// Every edge of a mesh once, With the faces on either side of it
+struct Edges {
+    // Index of each edge, Keyed by its vertices with the smaller one first
+    index: HashMap<(usize, usize), usize>,
+    vertices: Vec<(usize, usize)>,
+    faces: Vec<Vec<usize>>,
+}
+
+impl Edges {
+    fn new(mesh: &PolygonMesh) -> Self {
+        let mut edges = Self {
+            index: HashMap::new(),
+            vertices: Vec::new(),
+            faces: Vec::new(),
+        };
+
+        for (f, face) in mesh.faces.iter().enumerate() {
+            for (i, &a) in face.iter().enumerate() {
+                let b = face[(i + 1) % face.len()];
+                let key = (a.min(b), a.max(b));
+
+                let next = edges.vertices.len();
+                let e = *edges.index.entry(key).or_insert(next);
+                if e == next {
+                    edges.vertices.push(key);
+                    edges.faces.push(Vec::new());
+                }
+                edges.faces[e].push(f);
+            }
+        }
+
+        edges
+    }
+
+    fn find(&self, a: usize, b: usize) -> usize {
+        self.index[&(a.min(b), a.max(b))]
+    }
+
+    /// Edges with a face on only one side
+    fn is_boundary(&self, e: usize) -> bool {
+        self.faces[e].len() != 2
+    }
+
+    /// Edges touching each vertex
+    fn around_vertices(&self, count: usize) -> Vec<Vec<usize>> {
+        let mut around = vec![Vec::new(); count];
+        for (e, &(a, b)) in self.vertices.iter().enumerate() {
+            around[a].push(e);
+            around[b].push(e);
+        }
+
+        around
+    }
+
+    fn other(&self, e: usize, v: usize) -> usize {
+        let (a, b) = self.vertices[e];
+        if a == v {
+            b
+        } else {
+            a
+        }
+    }
+
+    /// On a boundary a vertex only follows the two boundary edges next to it, So that open
+    /// edges of the mesh stay where they are instead of shrinking inwards.
+    /// None if the vertex isn't on a boundary
+    fn boundary_rule(&self, mesh: &PolygonMesh, v: usize, around: &[usize]) -> Option<Vec3> {
+        let boundary: Vec<_> = around
+            .iter()
+            .filter(|&&e| self.is_boundary(e))
+            .map(|&e| mesh.positions[self.other(e, v)])
+            .collect();
+
+        match boundary.len() {
+            0 => None,
+            2 => Some(mesh.positions[v] * 0.75 + (boundary[0] + boundary[1]) * 0.125),
+            // Corners where more than 2 open edges meet don't move
+            _ => Some(mesh.positions[v]),
+        }
+    }
+}
+
+fn midpoint(mesh: &PolygonMesh, (a, b): (usize, usize)) -> (Vec3, (f64, f64)) {
+    let (uv_a, uv_b) = (mesh.uvs[a], mesh.uvs[b]);
+
+    (
+        (mesh.positions[a] + mesh.positions[b]) * 0.5,
+        ((uv_a.0 + uv_b.0) * 0.5, (uv_a.1 + uv_b.1) * 0.5),
+    )
+}
+
+/// One level of Loop subdivision, The mesh has to be made of triangles.
+/// New vertices are added after the old ones, One in the middle of each edge
+pub(super) fn loop_step(mesh: &PolygonMesh) -> PolygonMesh {
+    let edges = Edges::new(mesh);
+    let around = edges.around_vertices(mesh.positions.len());
+
+    let mut positions = Vec::with_capacity(mesh.positions.len() + edges.vertices.len());
+    let mut uvs = mesh.uvs.clone();
+
+    for (v, around) in around.iter().enumerate() {
+        let position = edges.boundary_rule(mesh, v, around).unwrap_or_else(|| {
+            let n = around.len();
+            let beta = if n == 3 {
+                3.0 / 16.0
+            } else {
+                3.0 / (8.0 * n as f64)
+            };
+            let neighbours = around.iter().fold(Vec3::splat(0.0), |acc, &e| {
+                acc + mesh.positions[edges.other(e, v)]
+            });
+
+            mesh.positions[v] * (1.0 - n as f64 * beta) + neighbours * beta
+        });
+
+        positions.push(position);
+    }
+
+    for (e, &(a, b)) in edges.vertices.iter().enumerate() {
+        let (mid, uv) = midpoint(mesh, (a, b));
+
+        let position = if edges.is_boundary(e) {
+            mid
+        } else {
+            // The corners across from the edge in the two triangles next to it
+            let opposite = edges.faces[e].iter().fold(Vec3::splat(0.0), |acc, &f| {
+                let corner = mesh.faces[f].iter().find(|&&v| v != a && v != b).unwrap();
+                acc + mesh.positions[*corner]
+            });
+
+            mid * 0.75 + opposite * 0.125
+        };
+
+        positions.push(position);
+        uvs.push(uv);
+    }
+
+    let offset = mesh.positions.len();
+    let faces = mesh
+        .faces
+        .iter()
+        .flat_map(|face| {
+            let (a, b, c) = (face[0], face[1], face[2]);
+            let ab = offset + edges.find(a, b);
+            let bc = offset + edges.find(b, c);
+            let ca = offset + edges.find(c, a);
+
+            vec![
+                vec![a, ab, ca],
+                vec![ab, b, bc],
+                vec![ca, bc, c],
+                vec![ab, bc, ca],
+            ]
+        })
+        .collect();
+
+    PolygonMesh {
+        positions,
+        uvs,
+        faces,
+    }
+}
+
+/// One level of Catmull-Clark subdivision, Works on any polygons but always gives back quads.
+/// New vertices are added after the old ones, First one per face & then one per edge
+pub(super) fn catmull_clark_step(mesh: &PolygonMesh) -> PolygonMesh {
+    let edges = Edges::new(mesh);
+    let around = edges.around_vertices(mesh.positions.len());
+
+    let face_points: Vec<_> = mesh
+        .faces
+        .iter()
+        .map(|face| {
+            let n = face.len() as f64;
+            let (position, (u, v)) =
+                face.iter()
+                    .fold((Vec3::splat(0.0), (0.0, 0.0)), |(p, (u, v)), &i| {
+                        (
+                            p + mesh.positions[i],
+                            (u + mesh.uvs[i].0, v + mesh.uvs[i].1),
+                        )
+                    });
+
+            (position / n, (u / n, v / n))
+        })
+        .collect();
+
+    let edge_points: Vec<_> = edges
+        .vertices
+        .iter()
+        .enumerate()
+        .map(|(e, &(a, b))| {
+            let (mid, uv) = midpoint(mesh, (a, b));
+            if edges.is_boundary(e) {
+                return (mid, uv);
+            }
+
+            let faces = edges.faces[e]
+                .iter()
+                .fold(Vec3::splat(0.0), |acc, &f| acc + face_points[f].0);
+
+            ((mid + faces * 0.5) * 0.5, uv)
+        })
+        .collect();
+
+    let mut positions =
+        Vec::with_capacity(mesh.positions.len() + face_points.len() + edge_points.len());
+    let mut uvs = mesh.uvs.clone();
+
+    for (v, around) in around.iter().enumerate() {
+        let position = edges.boundary_rule(mesh, v, around).unwrap_or_else(|| {
+            let n = around.len() as f64;
+
+            // Average of the face points & edge midpoints around the vertex
+            let mut faces: Vec<_> = around.iter().flat_map(|&e| &edges.faces[e]).collect();
+            faces.sort_unstable();
+            faces.dedup();
+            let f = faces
+                .iter()
+                .fold(Vec3::splat(0.0), |acc, &&f| acc + face_points[f].0)
+                / faces.len() as f64;
+            let r = around.iter().fold(Vec3::splat(0.0), |acc, &e| {
+                acc + midpoint(mesh, edges.vertices[e]).0
+            }) / n;
+
+            (f + r * 2.0 + mesh.positions[v] * (n - 3.0)) / n
+        });
+
+        positions.push(position);
+    }
+
+    for &(position, uv) in face_points.iter().chain(&edge_points) {
+        positions.push(position);
+        uvs.push(uv);
+    }
+
+    let face_offset = mesh.positions.len();
+    let edge_offset = face_offset + face_points.len();
+    let faces = mesh
+        .faces
+        .iter()
+        .enumerate()
+        .flat_map(|(f, face)| {
+            let k = face.len();
+            let edge_point = |a: usize, b: usize| edge_offset + edges.find(a, b);
+
+            (0..k)
+                .map(|i| {
+                    let (prev, v, next) = (face[(i + k - 1) % k], face[i], face[(i + 1) % k]);
+                    vec![v, edge_point(v, next), face_offset + f, edge_point(prev, v)]
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect();
+
+    PolygonMesh {
+        positions,
+        uvs,
+        faces,
+    }
+}
@@ -0,0 +1,130 @@
+use std::sync::Arc;
+
+use rand::{prelude::SmallRng, SeedableRng};
+
+use crate::{
+    hitable::{
+        mesh::PolygonMesh,
+        shapes::{intersect_triangle, uv_tangents},
+        BvhNode, HitRecord, Hitable,
+    },
+    types::{Ray, Vec3},
+    Aabb, Material,
+};
+
+struct MeshData<T: Material + Clone + Sized> {
+    positions: Vec<Vec3>,
+    normals: Vec<Vec3>,
+    uvs: Vec<(f64, f64)>,
+    triangles: Vec<[usize; 3]>,
+    material: T,
+}
+
+/// One triangle of a mesh, All of them share the vertices & material of the mesh
+#[derive(Clone)]
+struct MeshTriangle<T: Material + Clone + Sized> {
+    mesh: Arc<MeshData<T>>,
+    index: usize,
+}
+
+impl<T: Material + Clone + Sized> MeshTriangle<T> {
+    fn corners(&self) -> [usize; 3] {
+        self.mesh.triangles[self.index]
+    }
+}
+
+impl<T: Material + Clone + Sized> Hitable for MeshTriangle<T> {
+    fn hit(&self, ray: &Ray, t_min: f64, t_max: f64) -> Option<HitRecord> {
+        let [a, b, c] = self.corners();
+        let mesh = &self.mesh;
+        let (ab, ac) = (
+            mesh.positions[b] - mesh.positions[a],
+            mesh.positions[c] - mesh.positions[a],
+        );
+
+        let (t, b1, b2) = intersect_triangle(ray, mesh.positions[a], ab, ac)?;
+        if t < t_min || t > t_max {
+            return None;
+        }
+
+        let b0 = 1.0 - b1 - b2;
+        let uvs = [mesh.uvs[a], mesh.uvs[b], mesh.uvs[c]];
+        let [(u0, v0), (u1, v1), (u2, v2)] = uvs;
+        let uv = (b0 * u0 + b1 * u1 + b2 * u2, b0 * v0 + b1 * v1 + b2 * v2);
+
+        let smooth = mesh.normals[a] * b0 + mesh.normals[b] * b1 + mesh.normals[c] * b2;
+        let normal = if smooth.length() > 0.0 {
+            smooth.unit_vector()
+        } else {
+            ab.cross(&ac).unit_vector()
+        };
+
+        let (dpdu, dpdv) = uv_tangents(uvs, ab, ac, normal);
+
+        let mut hit_rec = HitRecord::new(t, ray.point_at_parameter(t), normal, &mesh.material, uv)
+            .with_tangents(dpdu, dpdv);
+
+        hit_rec.set_face_normal(ray);
+
+        Some(hit_rec)
+    }
+
+    fn bounding_box(&self, _t0: f64, _t1: f64) -> Option<Aabb> {
+        // Padded so that triangles lined up with an axis still have some thickness
+        let padding = Vec3::splat(0.0001);
+        let [a, b, c] = self.corners().map(|i| self.mesh.positions[i]);
+
+        Some(Aabb::new(
+            Vec3::min(Vec3::min(a, b), c) - padding,
+            Vec3::max(Vec3::max(a, b), c) + padding,
+        ))
+    }
+}
+
+/// Triangles of a polygon mesh in a BVH of their own, Shaded with normals smoothed over
+/// the vertices. Subdivide & displace the `PolygonMesh` first, The triangles are fixed
+/// once the mesh is built
+pub struct TriangleMesh<T: Material + Clone + Sized> {
+    bvh: BvhNode<MeshTriangle<T>>,
+}
+
+impl<T: Material + Clone + Sized> TriangleMesh<T> {
+    pub fn new(mesh: PolygonMesh, material: T) -> Self {
+        let mesh = mesh.triangulate();
+        let normals = mesh.vertex_normals();
+
+        let data = Arc::new(MeshData {
+            triangles: mesh.faces.iter().map(|f| [f[0], f[1], f[2]]).collect(),
+            positions: mesh.positions,
+            normals,
+            uvs: mesh.uvs,
+            material,
+        });
+
+        let mut triangles: Vec<_> = (0..data.triangles.len())
+            .map(|index| MeshTriangle {
+                mesh: Arc::clone(&data),
+                index,
+            })
+            .collect();
+        assert!(!triangles.is_empty(), "mesh needs at least one face");
+
+        // The axis each level is split on is picked at random, A fixed seed makes the
+        // same mesh always end up with the same tree
+        let mut rng = SmallRng::seed_from_u64(0);
+
+        Self {
+            bvh: BvhNode::new(&mut rng, &mut triangles, 0.0, 1.0),
+        }
+    }
+}
+
+impl<T: Material + Clone + Sized> Hitable for TriangleMesh<T> {
+    fn hit(&self, ray: &Ray, t_min: f64, t_max: f64) -> Option<HitRecord> {
+        self.bvh.hit(ray, t_min, t_max)
+    }
+
+    fn bounding_box(&self, t0: f64, t1: f64) -> Option<Aabb> {
+        self.bvh.bounding_box(t0, t1)
+    }
+}
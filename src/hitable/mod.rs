@@ -1,21 +1,30 @@
+mod alpha_mask;
 pub mod bvh;
+mod csg;
+mod flip_face;
 pub mod hitable_list;
-mod rotate;
+pub mod mesh;
+mod motion_transform;
+pub mod sdf;
 pub mod shapes;
-mod translate;
+mod transform;
 pub mod volume;
 
+pub use alpha_mask::AlphaMask;
 pub use bvh::*;
-pub use translate::*;
+pub use csg::{Csg, Operation};
+pub use flip_face::FlipFace;
+pub use motion_transform::{Keyframe, MotionTransform};
+pub use transform::Transform;
 
 use std::sync::Arc;
 
 use crate::{
-    hitable::rotate::Rotate,
     types::{Ray, Vec3},
-    Aabb, Material, X, Y, Z,
+    Aabb, Material, Texture,
 };
 
+#[derive(Clone)]
 pub struct HitRecord<'a> {
     ///  Rays are represented by A + t * B
     ///  where A is the source point and B destination point
@@ -30,6 +39,10 @@ pub struct HitRecord<'a> {
     /// i.e. A vector from Ray source to the point t
     pub p: Vec3,
 
+    /// p in the space of the object that was hit, Before a `Transform` moved it
+    /// into the world. Same as p for objects that aren't instanced
+    pub local_p: Vec3,
+
     /// unit outward facing normal
     pub normal: Vec3,
 
@@ -40,6 +53,22 @@ pub struct HitRecord<'a> {
     pub u: f64,
     pub v: f64,
 
+    /// partial derivatives of p with respect to u and v
+    /// Together with the normal, They form the tangent frame used by normal & bump maps
+    pub dpdu: Vec3,
+    pub dpdv: Vec3,
+
+    /// dpdu & dpdv in the same space as local_p
+    pub local_dpdu: Vec3,
+    pub local_dpdv: Vec3,
+
+    /// Width of the ray's cone at p in world units, 0.0 if it's unknown
+    /// Textures use it to pick how blurry the lookup should be
+    pub footprint: f64,
+
+    /// Time of the ray that hit, For textures that change over the course of a frame
+    pub time: f64,
+
     pub front_face: bool,
 }
 
@@ -51,17 +80,51 @@ impl<'a> HitRecord<'a> {
         material: &'a dyn Material,
         (u, v): (f64, f64),
     ) -> Self {
+        let (dpdu, dpdv) = Self::tangent_frame(normal);
+
         Self {
             t,
             p,
+            local_p: p,
             normal,
             material,
             u,
             v,
+            dpdu,
+            dpdv,
+            local_dpdu: dpdu,
+            local_dpdv: dpdv,
+            footprint: 0.0,
+            time: 0.0,
             front_face: false,
         }
     }
 
+    /// Replaces the arbitrary tangent frame picked by `new` with the
+    /// actual partial derivatives of the surface
+    pub fn with_tangents(mut self, dpdu: Vec3, dpdv: Vec3) -> Self {
+        self.dpdu = dpdu;
+        self.dpdv = dpdv;
+        self.local_dpdu = dpdu;
+        self.local_dpdv = dpdv;
+        self
+    }
+
+    /// Builds an orthonormal tangent & bitangent for the given normal.
+    /// Used for the shapes that don't have a natural parameterization
+    pub fn tangent_frame(normal: Vec3) -> (Vec3, Vec3) {
+        let a = if normal.x().abs() > 0.9 {
+            Vec3::new(0.0, 1.0, 0.0)
+        } else {
+            Vec3::new(1.0, 0.0, 0.0)
+        };
+
+        let dpdv = normal.cross(&a).unit_vector();
+        let dpdu = dpdv.cross(&normal);
+
+        (dpdu, dpdv)
+    }
+
     pub fn set_face_normal(&mut self, ray: &Ray) {
         self.front_face = ray.direction.dot(&self.normal) < 0.0;
 
@@ -78,32 +141,125 @@ pub trait Hitable {
 
     fn bounding_box(&self, _t0: f64, _t1: f64) -> Option<Aabb>;
 
-    fn translate(self, offset: impl Into<Vec3>) -> Translate<Self>
+    /// Every place between t_min & t_max where the ray crosses the surface, Sorted by t
+    /// By default it keeps calling `hit` from just past the last crossing, Objects that find
+    /// all of them at once anyway(like CSG) can return them directly
+    fn hit_all(&self, ray: &Ray, t_min: f64, t_max: f64) -> Vec<HitRecord> {
+        let mut hits = vec![];
+        let mut t_min = t_min;
+
+        while let Some(hit) = self.hit(ray, t_min, t_max) {
+            t_min = hit.t + 0.0001;
+            hits.push(hit);
+        }
+
+        hits
+    }
+
+    /// Whether the ray starts inside of the object & the crossings between t_min & t_max.
+    /// Only makes sense for closed objects, front_face tells whether the ray goes in or out
+    fn crossings(&self, ray: &Ray, t_min: f64, t_max: f64) -> (bool, Vec<HitRecord>) {
+        let hits = self.hit_all(ray, t_min, t_max);
+
+        // Without any crossings before t_max, The next one after it tells whether the ray
+        // is inside the whole time
+        let starts_inside = match hits.first() {
+            Some(hit) => !hit.front_face,
+            None => matches!(self.hit(ray, t_max, f64::MAX), Some(hit) if !hit.front_face),
+        };
+
+        (starts_inside, hits)
+    }
+
+    /// Ranges of t between t_min & t_max where the ray is inside of the object
+    /// A range starts at t_min if the ray starts inside & ends at t_max if it doesn't get out
+    fn intervals(&self, ray: &Ray, t_min: f64, t_max: f64) -> Vec<(f64, f64)> {
+        let (starts_inside, hits) = self.crossings(ray, t_min, t_max);
+        let mut intervals = vec![];
+        let mut enter = if starts_inside { Some(t_min) } else { None };
+
+        for hit in hits {
+            if hit.front_face {
+                enter = Some(hit.t);
+            } else if let Some(start) = enter.take() {
+                intervals.push((start, hit.t));
+            }
+        }
+
+        if let Some(start) = enter {
+            intervals.push((start, t_max));
+        }
+
+        intervals
+    }
+
+    /// Places the object with a `Transform`, Chaining more of these onto it adds them to
+    /// the same matrix instead of wrapping it again
+    fn translate(self, offset: impl Into<Vec3>) -> Transform<Self>
+    where
+        Self: Sized,
+    {
+        Transform::new(Arc::new(self)).translate(offset)
+    }
+
+    /// Counter clockwise by angle(in degrees) looking down the axis, Like all the rotations
+    fn rotate_x(self, angle: f64) -> Transform<Self>
+    where
+        Self: Sized,
+    {
+        Transform::new(Arc::new(self)).rotate_x(angle)
+    }
+
+    fn rotate_y(self, angle: f64) -> Transform<Self>
+    where
+        Self: Sized,
+    {
+        Transform::new(Arc::new(self)).rotate_y(angle)
+    }
+
+    fn rotate_z(self, angle: f64) -> Transform<Self>
     where
         Self: Sized,
     {
-        Translate::new(self, offset.into())
+        Transform::new(Arc::new(self)).rotate_z(angle)
     }
 
-    fn rotate_x(self, angle: f64) -> Rotate<X, Y, Z, Self>
+    fn flip_face(self) -> FlipFace<Self>
     where
         Self: Sized,
     {
-        Rotate::new(self, angle)
+        FlipFace::new(self)
     }
 
-    fn rotate_y(self, angle: f64) -> Rotate<Y, X, Z, Self>
+    fn alpha_mask<T: Texture>(self, mask: T) -> AlphaMask<Self, T>
     where
         Self: Sized,
     {
-        Rotate::new(self, angle)
+        AlphaMask::new(self, mask)
     }
 
-    fn rotate_z(self, angle: f64) -> Rotate<Z, Y, X, Self>
+    /// Everything that's inside of either object
+    fn union<B: Hitable>(self, other: B) -> Csg<Self, B>
     where
         Self: Sized,
     {
-        Rotate::new(self, angle)
+        Csg::new(self, other, Operation::Union)
+    }
+
+    /// Only the part that's inside of both objects
+    fn intersect<B: Hitable>(self, other: B) -> Csg<Self, B>
+    where
+        Self: Sized,
+    {
+        Csg::new(self, other, Operation::Intersection)
+    }
+
+    /// This object with other carved out of it
+    fn subtract<B: Hitable>(self, other: B) -> Csg<Self, B>
+    where
+        Self: Sized,
+    {
+        Csg::new(self, other, Operation::Difference)
     }
 }
 
@@ -114,4 +270,122 @@ impl<T: Hitable + ?Sized> Hitable for Arc<T> {
     fn bounding_box(&self, t0: f64, t1: f64) -> Option<Aabb> {
         self.as_ref().bounding_box(t0, t1)
     }
+    fn hit_all(&self, ray: &Ray, t_min: f64, t_max: f64) -> Vec<HitRecord> {
+        self.as_ref().hit_all(ray, t_min, t_max)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::SmallRng, SeedableRng};
+
+    use super::{
+        shapes::{Cuboid, Sphere},
+        Hitable,
+    };
+    use crate::{
+        materials::{Dielectric, Lambertian},
+        texture::Solid,
+        types::{Ray, Vec3},
+    };
+
+    // Follows a ray bouncing around inside of object, Returns the front_face & the
+    // scattered direction at every hit
+    fn trace(object: &impl Hitable, ray: Ray) -> Vec<(bool, Vec3)> {
+        let mut rng = SmallRng::seed_from_u64(42);
+        let mut ray = ray;
+        let mut path = vec![];
+
+        while let Some(hit) = object.hit(&ray, 0.001, f64::MAX) {
+            let (_, scattered) = hit.material.scatter(&ray, &hit, &mut rng);
+            let scattered = scattered.unwrap();
+
+            path.push((hit.front_face, scattered.direction));
+            ray = scattered;
+        }
+
+        path
+    }
+
+    #[test]
+    fn transformed_glass_refracts_like_untransformed_glass() {
+        let center = Vec3::new(3.0, -1.0, 2.0);
+        let glass = Sphere::new(center, 1.0, Dielectric::new(1.5));
+        let instanced = Sphere::new(Vec3::splat(0.0), 1.0, Dielectric::new(1.5))
+            .rotate_y(37.0)
+            .translate(center);
+
+        let ray = Ray::new(
+            Vec3::new(-5.0, 0.0, 0.0),
+            center + Vec3::new(8.0, -0.6, 0.3),
+            0.0,
+        );
+
+        let expected = trace(&glass, Ray::new(ray.origin, ray.direction, 0.0));
+        let actual = trace(&instanced, ray);
+
+        assert!(expected.len() >= 2);
+        assert!(expected[0].0 && !expected[1].0);
+        assert_eq!(expected.len(), actual.len());
+
+        for ((expected_face, expected_dir), (actual_face, actual_dir)) in
+            expected.iter().zip(actual.iter())
+        {
+            assert_eq!(expected_face, actual_face);
+            assert!((*expected_dir - *actual_dir).length() < 1e-9);
+        }
+    }
+
+    pub(super) fn gray() -> Lambertian<Solid> {
+        Lambertian::new(Solid::new(Vec3::splat(0.5)))
+    }
+
+    pub(super) fn assert_intervals(actual: Vec<(f64, f64)>, expected: &[(f64, f64)]) {
+        assert_eq!(actual.len(), expected.len(), "{:?}", actual);
+
+        for (actual, expected) in actual.iter().zip(expected.iter()) {
+            assert!((actual.0 - expected.0).abs() < 1e-9, "{:?}", actual);
+            assert!((actual.1 - expected.1).abs() < 1e-9, "{:?}", actual);
+        }
+    }
+
+    #[test]
+    fn sphere_intervals_from_outside_and_inside() {
+        let sphere = Sphere::new(Vec3::splat(0.0), 1.0, gray());
+        let direction = Vec3::new(1.0, 0.0, 0.0);
+
+        let outside = Ray::new(Vec3::new(-5.0, 0.0, 0.0), direction, 0.0);
+        assert_intervals(sphere.intervals(&outside, 0.001, f64::MAX), &[(4.0, 6.0)]);
+        assert_intervals(sphere.intervals(&outside, 0.001, 5.0), &[(4.0, 5.0)]);
+        assert_intervals(sphere.intervals(&outside, 0.001, 3.0), &[]);
+
+        let inside = Ray::new(Vec3::new(0.5, 0.0, 0.0), direction, 0.0);
+        assert_intervals(sphere.intervals(&inside, 0.001, f64::MAX), &[(0.001, 0.5)]);
+        assert_intervals(sphere.intervals(&inside, 0.001, 0.3), &[(0.001, 0.3)]);
+    }
+
+    #[test]
+    fn cuboid_front_face_on_entry_and_exit() {
+        let cuboid = Cuboid::new(Vec3::splat(-1.0), Vec3::splat(1.0), gray());
+
+        // Through every pair of opposite faces in both directions
+        for axis in 0..3 {
+            for &sign in [-1.0, 1.0].iter() {
+                let mut direction = Vec3::splat(0.0);
+                direction[axis] = sign;
+                // A little off center so that the ray doesn't go through the edges
+                let mut offset = Vec3::splat(0.1);
+                offset[axis] = 0.0;
+                let ray = Ray::new(direction * -5.0 + offset, direction, 0.0);
+
+                let hits = cuboid.hit_all(&ray, 0.001, f64::MAX);
+                assert_eq!(hits.len(), 2);
+                assert!((hits[0].t - 4.0).abs() < 1e-9 && hits[0].front_face);
+                assert!((hits[1].t - 6.0).abs() < 1e-9 && !hits[1].front_face);
+
+                // Normals always face against the ray
+                assert!(hits.iter().all(|hit| hit.normal.dot(&direction) < 0.0));
+            }
+        }
+    }
 }
@@ -0,0 +1,155 @@
+use std::sync::Arc;
+
+use crate::{
+    hitable::{
+        transform::{to_local, to_world, transform_box},
+        HitRecord, Hitable,
+    },
+    types::{Mat4, Quaternion, Ray, Vec3},
+    Aabb,
+};
+
+// Number of pieces the motion is cut into when building the bounding box
+const BOUNDING_STEPS: usize = 16;
+
+/// Placement of an object at one point in time, Applied as scale, Then rotation &
+/// lastly translation
+#[derive(Debug, Copy, Clone)]
+pub struct Keyframe {
+    translation: Vec3,
+    rotation: Quaternion,
+    scale: Vec3,
+}
+
+impl Keyframe {
+    pub fn new() -> Self {
+        Self {
+            translation: Vec3::splat(0.0),
+            rotation: Quaternion::identity(),
+            scale: Vec3::splat(1.0),
+        }
+    }
+
+    pub fn with_translation(mut self, offset: impl Into<Vec3>) -> Self {
+        self.translation = offset.into();
+        self
+    }
+
+    /// Rotation by angle(in degrees) about axis, Angles over 180 degrees can't be told
+    /// apart from the shorter rotation the other way around, That's the one that's used
+    pub fn with_rotation(mut self, axis: impl Into<Vec3>, angle: f64) -> Self {
+        self.rotation = Quaternion::from_axis_angle(axis.into(), angle);
+        self
+    }
+
+    pub fn with_scale(mut self, factor: impl Into<Vec3>) -> Self {
+        self.scale = factor.into();
+        self
+    }
+
+    fn lerp(&self, other: &Self, t: f64) -> Self {
+        Self {
+            translation: self.translation + (other.translation - self.translation) * t,
+            rotation: self.rotation.slerp(other.rotation, t),
+            scale: self.scale + (other.scale - self.scale) * t,
+        }
+    }
+
+    fn matrix(&self) -> Mat4 {
+        Mat4::translation(self.translation) * self.rotation.to_matrix() * Mat4::scale(self.scale)
+    }
+
+    // Inverse of each of the parts is trivial, Which is much cheaper than inverting the
+    // whole matrix on every hit
+    fn inverse(&self) -> Mat4 {
+        Mat4::scale(Vec3::splat(1.0) / self.scale)
+            * self.rotation.to_matrix().transpose()
+            * Mat4::translation(-self.translation)
+    }
+}
+
+impl Default for Keyframe {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Moves, Spins & resizes any object over the shutter time, Like `MovingSphere` does
+/// with its center. Rotation is interpolated with slerp so it spins at a constant speed
+pub struct MotionTransform<T: ?Sized> {
+    object: Arc<T>,
+    start: Keyframe,
+    end: Keyframe,
+    time_start: f64,
+    time_end: f64,
+}
+
+impl<T: Hitable + ?Sized> MotionTransform<T> {
+    pub fn new(
+        object: Arc<T>,
+        start: Keyframe,
+        end: Keyframe,
+        time_start: f64,
+        time_end: f64,
+    ) -> Self {
+        Self {
+            object,
+            start,
+            end,
+            time_start,
+            time_end,
+        }
+    }
+
+    fn keyframe(&self, time: f64) -> Keyframe {
+        let t = ((time - self.time_start) / (self.time_end - self.time_start)).clamp(0.0, 1.0);
+
+        self.start.lerp(&self.end, t)
+    }
+}
+
+impl<T: Hitable + ?Sized> Hitable for MotionTransform<T> {
+    fn hit(&self, ray: &Ray, t_min: f64, t_max: f64) -> Option<HitRecord> {
+        let keyframe = self.keyframe(ray.time());
+        let inverse = keyframe.inverse();
+
+        let hit = self.object.hit(&to_local(ray, &inverse), t_min, t_max)?;
+
+        Some(to_world(hit, &keyframe.matrix(), &inverse.transpose()))
+    }
+
+    fn bounding_box(&self, t0: f64, t1: f64) -> Option<Aabb> {
+        let bbox = self.object.bounding_box(t0, t1)?;
+
+        let mut total: Option<Aabb> = None;
+        let mut radius = 0.0f64;
+
+        for i in 0..=BOUNDING_STEPS {
+            let keyframe = self.keyframe(t0 + (t1 - t0) * i as f64 / BOUNDING_STEPS as f64);
+            let step = transform_box(&bbox, &keyframe.matrix());
+
+            // Farthest any point of the box can be from the center of rotation
+            let far = Vec3::max(
+                keyframe.translation - step.min,
+                step.max - keyframe.translation,
+            );
+            radius = radius.max(far.length());
+
+            total = Some(match total {
+                Some(total) => Aabb::surrounding_box(total, step),
+                None => step,
+            });
+        }
+
+        // Between two of the steps the corners move along an arc instead of a straight line
+        // An arc of angle a bulges out by at most (1 / cos(a / 2) - 1) of its radius
+        let angle = self
+            .keyframe(t0)
+            .rotation
+            .angle_to(&self.keyframe(t1).rotation)
+            / BOUNDING_STEPS as f64;
+        let padding = Vec3::splat(radius * (1.0 / (angle / 2.0).cos() - 1.0));
+
+        total.map(|bbox| Aabb::new(bbox.min - padding, bbox.max + padding))
+    }
+}
@@ -0,0 +1,48 @@
+//! Shapes described by signed distance functions instead of a ray intersection formula.
+//! Negative inside, Positive outside & the magnitude is how far away the surface is
+//! (or a lower bound on it), So a ray can step that far without going through anything
+
+mod operations;
+mod primitives;
+mod sdf_shape;
+
+pub use operations::{Displace, Offset, SmoothUnion};
+pub use primitives::{Ball, Mandelbulb, RoundedBox};
+pub use sdf_shape::SdfShape;
+
+use crate::types::Vec3;
+
+pub trait Sdf {
+    fn distance(&self, p: Vec3) -> f64;
+
+    /// Moves the shape by offset
+    fn offset(self, offset: Vec3) -> Offset<Self>
+    where
+        Self: Sized,
+    {
+        Offset::new(self, offset)
+    }
+
+    /// Union of the two shapes with the seam rounded over a distance of about k
+    fn smooth_union<S: Sdf>(self, other: S, k: f64) -> SmoothUnion<Self, S>
+    where
+        Self: Sized,
+    {
+        SmoothUnion::new(self, other, k)
+    }
+
+    /// Pushes the surface out by displacement(p), Pulls it in where that's negative
+    fn displace<F: Fn(Vec3) -> f64>(self, displacement: F) -> Displace<Self, F>
+    where
+        Self: Sized,
+    {
+        Displace::new(self, displacement)
+    }
+}
+
+/// Any closure from a point to its distance is a SDF too
+impl<F: Fn(Vec3) -> f64> Sdf for F {
+    fn distance(&self, p: Vec3) -> f64 {
+        self(p)
+    }
+}
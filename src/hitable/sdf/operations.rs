@@ -0,0 +1,66 @@
+use crate::{hitable::sdf::Sdf, types::Vec3};
+
+pub struct Offset<S> {
+    sdf: S,
+    offset: Vec3,
+}
+
+impl<S: Sdf> Offset<S> {
+    pub fn new(sdf: S, offset: Vec3) -> Self {
+        Self { sdf, offset }
+    }
+}
+
+impl<S: Sdf> Sdf for Offset<S> {
+    fn distance(&self, p: Vec3) -> f64 {
+        self.sdf.distance(p - self.offset)
+    }
+}
+
+/// Union where the two shapes melt into each other instead of meeting at a crease
+pub struct SmoothUnion<A, B> {
+    a: A,
+    b: B,
+    k: f64,
+}
+
+impl<A: Sdf, B: Sdf> SmoothUnion<A, B> {
+    pub fn new(a: A, b: B, k: f64) -> Self {
+        Self { a, b, k }
+    }
+}
+
+impl<A: Sdf, B: Sdf> Sdf for SmoothUnion<A, B> {
+    fn distance(&self, p: Vec3) -> f64 {
+        let (a, b) = (self.a.distance(p), self.b.distance(p));
+
+        if self.k <= 0.0 {
+            return a.min(b);
+        }
+
+        // Polynomial smooth minimum, Only differs from min when a & b are within k
+        let h = (0.5 + 0.5 * (b - a) / self.k).clamp(0.0, 1.0);
+
+        b + (a - b) * h - self.k * h * (1.0 - h)
+    }
+}
+
+/// Adds a function of the position to the distance, e.g. ripples or noise on the surface.
+/// The result is no longer an exact distance, So steps have to be scaled down with
+/// `SdfShape::with_step_scale` by about 1 / (1 + steepest slope of the displacement)
+pub struct Displace<S, F> {
+    sdf: S,
+    displacement: F,
+}
+
+impl<S: Sdf, F: Fn(Vec3) -> f64> Displace<S, F> {
+    pub fn new(sdf: S, displacement: F) -> Self {
+        Self { sdf, displacement }
+    }
+}
+
+impl<S: Sdf, F: Fn(Vec3) -> f64> Sdf for Displace<S, F> {
+    fn distance(&self, p: Vec3) -> f64 {
+        self.sdf.distance(p) - (self.displacement)(p)
+    }
+}
@@ -0,0 +1,115 @@
+use crate::{hitable::sdf::Sdf, types::Vec3};
+
+/// Sphere centered at the origin
+#[derive(Debug, Copy, Clone)]
+pub struct Ball {
+    radius: f64,
+}
+
+impl Ball {
+    pub fn new(radius: f64) -> Self {
+        Self { radius }
+    }
+}
+
+impl Sdf for Ball {
+    fn distance(&self, p: Vec3) -> f64 {
+        p.length() - self.radius
+    }
+}
+
+/// Box centered at the origin with its edges & corners rounded off by radius.
+/// half_size is measured to the flat faces, So the radius doesn't make it any bigger
+#[derive(Debug, Copy, Clone)]
+pub struct RoundedBox {
+    half_size: Vec3,
+    radius: f64,
+}
+
+impl RoundedBox {
+    pub fn new(half_size: Vec3, radius: f64) -> Self {
+        Self { half_size, radius }
+    }
+}
+
+impl Sdf for RoundedBox {
+    fn distance(&self, p: Vec3) -> f64 {
+        let p = Vec3::new(p.x().abs(), p.y().abs(), p.z().abs());
+        let q = p - self.half_size + Vec3::splat(self.radius);
+
+        let outside = Vec3::max(q, Vec3::splat(0.0)).length();
+        let inside = q.x().max(q.y()).max(q.z()).min(0.0);
+
+        outside + inside - self.radius
+    }
+}
+
+/// 3D Mandelbrot set, Fits inside a ball of radius ~1.2 around the origin.
+/// The distance is only an estimate, So the `SdfShape` around it should take smaller steps
+#[derive(Debug, Copy, Clone)]
+pub struct Mandelbulb {
+    power: f64,
+    iterations: usize,
+}
+
+impl Mandelbulb {
+    pub fn new() -> Self {
+        Self {
+            power: 8.0,
+            iterations: 10,
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn with_power(mut self, power: f64) -> Self {
+        self.power = power;
+        self
+    }
+
+    /// More iterations add finer detail, At a cost of course
+    pub fn with_iterations(mut self, iterations: usize) -> Self {
+        self.iterations = iterations;
+        self
+    }
+}
+
+impl Default for Mandelbulb {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Sdf for Mandelbulb {
+    fn distance(&self, p: Vec3) -> f64 {
+        let mut z = p;
+        // Running derivative of the orbit, Turns how fast it escapes into a distance
+        let mut dr = 1.0;
+        let mut r = z.length();
+
+        for _ in 0..self.iterations {
+            if r > 2.0 {
+                break;
+            }
+
+            // z^power in spherical coordinates with Y up
+            let theta = (z.y() / r.max(1e-12)).clamp(-1.0, 1.0).acos() * self.power;
+            let phi = z.z().atan2(z.x()) * self.power;
+
+            dr = r.powf(self.power - 1.0) * self.power * dr + 1.0;
+
+            z = Vec3::new(
+                theta.sin() * phi.cos(),
+                theta.cos(),
+                theta.sin() * phi.sin(),
+            ) * r.powf(self.power)
+                + p;
+            r = z.length();
+        }
+
+        if r < 1e-12 {
+            return 0.0;
+        }
+
+        0.5 * r.ln() * r / dr
+    }
+}
@@ -0,0 +1,167 @@
+use crate::{
+    hitable::{sdf::Sdf, HitRecord, Hitable},
+    types::{Ray, Vec3},
+    Aabb, Material,
+};
+
+/// Renders a SDF by sphere tracing, i.e. stepping along the ray by the distance to the
+/// nearest surface until that distance is close enough to 0
+///
+/// The SDF is only marched inside bounds, Which has to contain the whole surface & is
+/// what the BVH sees. Normals come from the gradient of the distance & UVs from projecting
+/// p along whichever axis the normal faces the most, Like `Triplanar` without the blending
+pub struct SdfShape<S, T: Material + Clone + Sized> {
+    sdf: S,
+    bounds: Aabb,
+    material: T,
+    max_steps: usize,
+    // Distance to the surface that counts as a hit
+    epsilon: f64,
+    // Fraction of the distance bound to step by, Below 1.0 for SDFs that overestimate
+    step_scale: f64,
+    // UVs repeat every 1 / uv_scale units
+    uv_scale: f64,
+}
+
+impl<S: Sdf, T: Material + Clone + Sized> SdfShape<S, T> {
+    pub fn new(sdf: S, bounds: Aabb, material: T) -> Self {
+        Self {
+            sdf,
+            bounds,
+            material,
+            max_steps: 256,
+            epsilon: 1e-4,
+            step_scale: 1.0,
+            uv_scale: 1.0,
+        }
+    }
+
+    pub fn with_max_steps(mut self, max_steps: usize) -> Self {
+        self.max_steps = max_steps;
+        self
+    }
+
+    #[allow(dead_code)]
+    pub fn with_epsilon(mut self, epsilon: f64) -> Self {
+        self.epsilon = epsilon;
+        self
+    }
+
+    pub fn with_step_scale(mut self, step_scale: f64) -> Self {
+        self.step_scale = step_scale.clamp(0.01, 1.0);
+        self
+    }
+
+    pub fn with_uv_scale(mut self, uv_scale: f64) -> Self {
+        self.uv_scale = uv_scale;
+        self
+    }
+
+    /// Central differences over the corners of a tetrahedron, 4 lookups instead of 6
+    fn gradient(&self, p: Vec3) -> Vec3 {
+        let h = self.epsilon;
+        let corners = [
+            Vec3::new(1.0, -1.0, -1.0),
+            Vec3::new(-1.0, -1.0, 1.0),
+            Vec3::new(-1.0, 1.0, -1.0),
+            Vec3::new(1.0, 1.0, 1.0),
+        ];
+
+        corners.iter().fold(Vec3::splat(0.0), |acc, &k| {
+            acc + k * self.sdf.distance(p + k * h)
+        })
+    }
+
+    fn uv_and_tangents(&self, p: Vec3, normal: Vec3) -> ((f64, f64), Vec3, Vec3) {
+        let (nx, ny, nz) = (normal.x().abs(), normal.y().abs(), normal.z().abs());
+
+        // Same projections as `Triplanar`
+        let ((u, v), u_axis, v_axis) = if nx >= ny && nx >= nz {
+            (
+                (p.z(), p.y()),
+                Vec3::new(0.0, 0.0, 1.0),
+                Vec3::new(0.0, 1.0, 0.0),
+            )
+        } else if ny >= nz {
+            (
+                (p.x(), p.z()),
+                Vec3::new(1.0, 0.0, 0.0),
+                Vec3::new(0.0, 0.0, 1.0),
+            )
+        } else {
+            (
+                (p.x(), p.y()),
+                Vec3::new(1.0, 0.0, 0.0),
+                Vec3::new(0.0, 1.0, 0.0),
+            )
+        };
+
+        let uv = (
+            (u * self.uv_scale).rem_euclid(1.0),
+            (v * self.uv_scale).rem_euclid(1.0),
+        );
+
+        // Projection axes flattened onto the surface
+        let dpdu = (u_axis - normal * normal.dot(&u_axis)) / self.uv_scale;
+        let dpdv = (v_axis - normal * normal.dot(&v_axis)) / self.uv_scale;
+
+        (uv, dpdu, dpdv)
+    }
+}
+
+impl<S: Sdf, T: Material + Clone + Sized> Hitable for SdfShape<S, T> {
+    fn hit(&self, ray: &Ray, t_min: f64, t_max: f64) -> Option<HitRecord> {
+        let (start, end) = self.bounds.clip(ray, t_min, t_max)?;
+        let speed = ray.direction.length();
+
+        let start_distance = self.sdf.distance(ray.point_at_parameter(start));
+
+        // Rays that bounced or refracted off the surface start right on it,
+        // The direction tells which side they are going to. Rays coming in through the
+        // bounds are always outside, Even where the surface touches the bounds
+        let on_surface = start == t_min && start_distance.abs() < self.epsilon;
+        let side = if on_surface {
+            self.gradient(ray.point_at_parameter(start))
+                .dot(&ray.direction)
+                .signum()
+        } else if start > t_min {
+            1.0
+        } else {
+            start_distance.signum()
+        };
+
+        let mut left_surface = !on_surface;
+        let mut t = start;
+
+        for _ in 0..self.max_steps {
+            let p = ray.point_at_parameter(t);
+            let distance = self.sdf.distance(p) * side;
+
+            if distance < self.epsilon {
+                if left_surface {
+                    let normal = self.gradient(p).unit_vector();
+                    let (uv, dpdu, dpdv) = self.uv_and_tangents(p, normal);
+
+                    let mut hit_rec =
+                        HitRecord::new(t, p, normal, &self.material, uv).with_tangents(dpdu, dpdv);
+                    hit_rec.set_face_normal(ray);
+
+                    return Some(hit_rec);
+                }
+            } else {
+                left_surface = true;
+            }
+
+            t += distance.abs().max(self.epsilon) * self.step_scale / speed;
+            if t > end {
+                return None;
+            }
+        }
+
+        None
+    }
+
+    fn bounding_box(&self, _t0: f64, _t1: f64) -> Option<Aabb> {
+        Some(self.bounds)
+    }
+}
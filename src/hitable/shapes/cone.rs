@@ -0,0 +1,109 @@
+use crate::{
+    hitable::{
+        shapes::{
+            polynomial::solve_quadratic,
+            quadric::{around_axis, azimuth, nearest, sweep_radians, Cap},
+        },
+        HitRecord, Hitable,
+    },
+    types::{Ray, Vec3},
+    Aabb, Material,
+};
+
+/// Cone around the Y axis with its base of radius on y = 0 & the tip at y = height
+/// Open at the bottom unless capped, Put it anywhere else with a `Transform`
+#[derive(Clone)]
+pub struct Cone<T: Material + Clone + Sized> {
+    radius: f64,
+    height: f64,
+    phi_max: f64,
+    capped: bool,
+    material: T,
+}
+
+impl<T: Material + Clone + Sized> Cone<T> {
+    pub fn new(radius: f64, height: f64, material: T) -> Self {
+        Self {
+            radius,
+            height,
+            phi_max: sweep_radians(360.0),
+            capped: false,
+            material,
+        }
+    }
+
+    /// Only keeps the part from +X up to degrees around the axis, Towards +Z
+    pub fn with_sweep(mut self, degrees: f64) -> Self {
+        self.phi_max = sweep_radians(degrees);
+        self
+    }
+
+    /// Closes off the base with a flat cap
+    pub fn capped(mut self) -> Self {
+        self.capped = true;
+        self
+    }
+
+    fn base(&self) -> Cap {
+        Cap {
+            y: 0.0,
+            radius: self.radius,
+            facing: -1.0,
+        }
+    }
+}
+
+impl<T: Material + Clone + Sized> Hitable for Cone<T> {
+    fn hit(&self, ray: &Ray, t_min: f64, t_max: f64) -> Option<HitRecord> {
+        let (o, d) = (ray.origin, ray.direction);
+
+        // x^2 + z^2 = k * (height - y)^2
+        let k = (self.radius / self.height).powi(2);
+        let oy = o.y() - self.height;
+
+        let a = d.x() * d.x() + d.z() * d.z() - k * d.y() * d.y();
+        let b = 2.0 * (o.x() * d.x() + o.z() * d.z() - k * d.y() * oy);
+        let c = o.x() * o.x() + o.z() * o.z() - k * oy * oy;
+
+        // Equation also holds for the mirrored cone above the tip
+        let side = nearest(solve_quadratic(a, b, c), t_min, t_max, |t| {
+            let p = ray.point_at_parameter(t);
+            p.y() >= 0.0 && p.y() <= self.height && azimuth(p) <= self.phi_max
+        });
+
+        let t_max = side.unwrap_or(t_max);
+        if self.capped {
+            let base = self.base();
+            if let Some(t) = base.hit(ray, t_min, t_max, self.phi_max) {
+                return Some(base.record(ray, t, self.phi_max, &self.material));
+            }
+        }
+
+        let t = side?;
+        let p = ray.point_at_parameter(t);
+        let phi = azimuth(p);
+
+        let normal = Vec3::new(p.x(), k * (self.height - p.y()), p.z()).unit_vector();
+        let uv = (phi / self.phi_max, p.y() / self.height);
+
+        // From the rim straight up to the tip
+        let dpdv = Vec3::new(
+            -self.radius * phi.cos(),
+            self.height,
+            -self.radius * phi.sin(),
+        );
+
+        let mut hit_rec = HitRecord::new(t, p, normal, &self.material, uv)
+            .with_tangents(around_axis(p, self.phi_max), dpdv);
+        hit_rec.set_face_normal(ray);
+
+        Some(hit_rec)
+    }
+
+    fn bounding_box(&self, _t0: f64, _t1: f64) -> Option<Aabb> {
+        Some(Aabb::new(
+            Vec3::new(-self.radius, 0.0, -self.radius),
+            Vec3::new(self.radius, self.height, self.radius),
+        ))
+    }
+}
@@ -78,7 +78,15 @@ impl Cuboid {
 
 impl Hitable for Cuboid {
     fn hit(&self, ray: &Ray, t_min: f64, t_max: f64) -> Option<HitRecord> {
-        self.sides.hit(ray, t_min, t_max)
+        let mut hit_rec = self.sides.hit(ray, t_min, t_max)?;
+
+        // Sides are plain rectangles whose normals all point along +axis, So half of them
+        // face into the box. Normal already points against the ray, The ray is on its way in
+        // when that is also away from the center
+        let center = (self.min + self.max) / 2.0;
+        hit_rec.front_face = hit_rec.normal.dot(&(hit_rec.p - center)) > 0.0;
+
+        Some(hit_rec)
     }
 
     fn bounding_box(&self, _t0: f64, _t1: f64) -> Option<Aabb> {
@@ -0,0 +1,360 @@
+use std::ops::{Add, Mul};
+
+use crate::{
+    hitable::{HitRecord, Hitable},
+    types::{Mat4, Ray, Vec3},
+    Aabb, Material,
+};
+
+#[derive(Debug, Copy, Clone)]
+enum Kind {
+    /// Flat strip that always faces the ray
+    Flat,
+    /// Same strip but with its normal bent around like on a cylinder
+    Tube,
+    /// Strip with a fixed orientation, Normal goes from the first to the second
+    Ribbon(Vec3, Vec3),
+}
+
+/// Cubic Bézier curve with a width, For hair, fur & blades of grass
+///
+/// u goes along the curve & v across it, From one edge to the other. Intersection splits
+/// the curve in halves until the pieces are nearly straight & then tests them as flat
+/// segments, See "Physically Based Rendering", Chapter 3.7
+#[derive(Clone)]
+pub struct Curve<T: Material + Clone + Sized> {
+    points: [Vec3; 4],
+    // Widths at the control points, Blended with the same weights as the points
+    widths: [f64; 4],
+    // Part of the original curve this is, After it's been cut up by `segments`
+    u_range: (f64, f64),
+    kind: Kind,
+    material: T,
+}
+
+impl<T: Material + Clone + Sized> Curve<T> {
+    pub fn new(points: [Vec3; 4], widths: [f64; 4], material: T) -> Self {
+        Self {
+            points,
+            widths,
+            u_range: (0.0, 1.0),
+            kind: Kind::Flat,
+            material,
+        }
+    }
+
+    /// Shades like a round fiber even though it's still intersected as a flat strip
+    pub fn tube(mut self) -> Self {
+        self.kind = Kind::Tube;
+        self
+    }
+
+    /// Strip facing along start_normal at the first point & end_normal at the last one,
+    /// Instead of turning towards every ray. Goes thin when seen edge on
+    pub fn oriented(mut self, start_normal: Vec3, end_normal: Vec3) -> Self {
+        self.kind = Kind::Ribbon(start_normal.unit_vector(), end_normal.unit_vector());
+        self
+    }
+
+    /// Cuts the curve into count pieces of the same parametric length. Each piece gets a
+    /// much tighter bounding box, Which makes a big difference to the BVH for long & curly
+    /// strands. u still goes over the whole original curve
+    pub fn segments(self, count: usize) -> Vec<Self> {
+        let (start, end) = self.u_range;
+
+        (0..count)
+            .map(|k| {
+                let a = k as f64 / count as f64;
+                let b = (k + 1) as f64 / count as f64;
+                let ts = [[a, a, a], [a, a, b], [a, b, b], [b, b, b]];
+
+                Self {
+                    points: ts.map(|t| blossom(self.points, t)),
+                    widths: ts.map(|t| blossom(self.widths, t)),
+                    u_range: (start + (end - start) * a, start + (end - start) * b),
+                    ..self.clone()
+                }
+            })
+            .collect()
+    }
+
+    fn width(&self, u: f64) -> f64 {
+        let [w0, w1, w2, w3] = self.widths;
+        let s = 1.0 - u;
+
+        w0 * s * s * s + 3.0 * w1 * s * s * u + 3.0 * w2 * s * u * u + w3 * u * u * u
+    }
+
+    fn max_width(&self) -> f64 {
+        self.widths.iter().fold(0.0, |acc: f64, &w| acc.max(w))
+    }
+
+    fn ribbon_normal(start: Vec3, end: Vec3, u: f64) -> Vec3 {
+        let angle = start.dot(&end).clamp(-1.0, 1.0).acos();
+
+        if angle < 1e-6 {
+            return start;
+        }
+
+        (start * ((1.0 - u) * angle).sin() + end * (u * angle).sin()) / angle.sin()
+    }
+
+    /// Walks down the halves of the curve in ray space until depth runs out.
+    /// Points are in a space where the ray starts at the origin & goes along +Z
+    #[allow(clippy::too_many_arguments)]
+    fn hit_segment(
+        &self,
+        points: &[Vec3; 4],
+        (u0, u1): (f64, f64),
+        depth: u32,
+        z_min: f64,
+        z_max: f64,
+        frame: &Frame,
+        ray: &Ray,
+    ) -> Option<HitRecord> {
+        // Widths blend like the points, So they never go over the widest control width
+        let half_width = self.max_width() * 0.5;
+
+        let (min, max) = points.iter().fold(
+            (Vec3::splat(f64::MAX), Vec3::splat(f64::MIN)),
+            |(min, max), &p| (Vec3::min(min, p), Vec3::max(max, p)),
+        );
+        if max.x() + half_width < 0.0
+            || min.x() - half_width > 0.0
+            || max.y() + half_width < 0.0
+            || min.y() - half_width > 0.0
+            || max.z() + half_width < z_min
+            || min.z() - half_width > z_max
+        {
+            return None;
+        }
+
+        if depth > 0 {
+            let (first, second) = split(points);
+            let middle = (u0 + u1) / 2.0;
+
+            let near = self.hit_segment(&first, (u0, middle), depth - 1, z_min, z_max, frame, ray);
+            let z_max = near.as_ref().map_or(z_max, |hit| hit.t * frame.speed);
+            let far = self.hit_segment(&second, (middle, u1), depth - 1, z_min, z_max, frame, ray);
+
+            return far.or(near);
+        }
+
+        self.hit_leaf(points, (u0, u1), z_min, z_max, frame, ray)
+    }
+
+    /// Tests a piece of the curve that's close enough to a straight line
+    fn hit_leaf(
+        &self,
+        points: &[Vec3; 4],
+        (u0, u1): (f64, f64),
+        z_min: f64,
+        z_max: f64,
+        frame: &Frame,
+        ray: &Ray,
+    ) -> Option<HitRecord> {
+        let [p0, p1, p2, p3] = *points;
+
+        // The ray has to be between the lines through the end points that are perpendicular
+        // to the ends of the curve, Otherwise it would hit the neighbouring piece instead
+        let start_edge = (p1.y() - p0.y()) * -p0.y() + p0.x() * (p0.x() - p1.x());
+        let end_edge = (p2.y() - p3.y()) * -p3.y() + p3.x() * (p3.x() - p2.x());
+        if start_edge < 0.0 || end_edge < 0.0 {
+            return None;
+        }
+
+        // Closest point to the ray on the line between the end points
+        let (dx, dy) = (p3.x() - p0.x(), p3.y() - p0.y());
+        let length_squared = dx * dx + dy * dy;
+        if length_squared == 0.0 {
+            return None;
+        }
+        let w = ((-p0.x() * dx - p0.y() * dy) / length_squared).clamp(0.0, 1.0);
+        let u = u0 + (u1 - u0) * w;
+
+        // u on the whole curve
+        let (u_start, u_end) = self.u_range;
+        let u_curve = u_start + (u_end - u_start) * u;
+
+        let strand_width = self.width(u);
+        let mut width = strand_width;
+        if let Kind::Ribbon(start, end) = self.kind {
+            let normal = Self::ribbon_normal(start, end, u_curve);
+            width *= normal.dot(&ray.direction).abs() / frame.speed;
+        }
+
+        let (pc, tangent) = evaluate(points, w);
+        let distance_squared = pc.x() * pc.x() + pc.y() * pc.y();
+        if distance_squared > width * width / 4.0 || pc.z() < z_min || pc.z() > z_max {
+            return None;
+        }
+
+        // Flat & tube strips turn to face every ray, So a ray scattered off the strand would
+        // hit it again right where it starts. The ray only gets this close to the middle of
+        // the strand when it starts on it
+        if pc.length() < strand_width {
+            return None;
+        }
+
+        // Which edge the ray is closer to
+        let distance = distance_squared.sqrt();
+        let side = tangent.x() * -pc.y() + pc.x() * tangent.y();
+        let v = if side > 0.0 {
+            0.5 + distance / width
+        } else {
+            0.5 - distance / width
+        };
+
+        let t = pc.z() / frame.speed;
+        let p = ray.point_at_parameter(t);
+
+        let (_, dpdu) = evaluate(&self.points, u);
+        let dpdu = dpdu / (u_end - u_start);
+        let dpdv = match self.kind {
+            Kind::Ribbon(start, end) => {
+                Self::ribbon_normal(start, end, u_curve)
+                    .cross(&dpdu)
+                    .unit_vector()
+                    * width
+            }
+            Kind::Flat | Kind::Tube => {
+                // Across the curve in the plane facing the ray
+                let dpdu_plane = frame.to_ray_space(dpdu);
+                let mut dpdv_plane =
+                    Vec3::new(-dpdu_plane.y(), dpdu_plane.x(), 0.0).unit_vector() * width;
+
+                if let Kind::Tube = self.kind {
+                    // Rotates it around the curve so that the normal turns away
+                    // from the ray towards the edges
+                    let theta = -90.0 + 180.0 * v;
+                    dpdv_plane = Mat4::rotation(dpdu_plane, -theta).transform_vector(dpdv_plane);
+                }
+
+                frame.to_world(dpdv_plane)
+            }
+        };
+
+        let normal = dpdu.cross(&dpdv).unit_vector();
+
+        let mut hit_rec =
+            HitRecord::new(t, p, normal, &self.material, (u_curve, v)).with_tangents(dpdu, dpdv);
+        hit_rec.set_face_normal(ray);
+
+        Some(hit_rec)
+    }
+}
+
+impl<T: Material + Clone + Sized> Hitable for Curve<T> {
+    fn hit(&self, ray: &Ray, t_min: f64, t_max: f64) -> Option<HitRecord> {
+        let frame = Frame::new(ray, self.points[3] - self.points[0]);
+        let points = self.points.map(|p| frame.to_ray_space(p - ray.origin));
+
+        // Enough halvings to get the pieces within 5% of the width of a straight line
+        let second_differences = [
+            points[0] - points[1] * 2.0 + points[2],
+            points[1] - points[2] * 2.0 + points[3],
+        ];
+        let l0 = second_differences.iter().fold(0.0, |acc: f64, d| {
+            acc.max(d.x().abs()).max(d.y().abs()).max(d.z().abs())
+        });
+        let epsilon = self.max_width() * 0.05;
+        let depth = if epsilon > 0.0 && l0 > 0.0 {
+            ((6.0 * std::f64::consts::SQRT_2 * l0 / (8.0 * epsilon)).log2() / 2.0).clamp(0.0, 10.0)
+                as u32
+        } else {
+            0
+        };
+
+        let z_min = t_min * frame.speed;
+        let z_max = t_max * frame.speed;
+
+        self.hit_segment(&points, (0.0, 1.0), depth, z_min, z_max, &frame, ray)
+    }
+
+    fn bounding_box(&self, _t0: f64, _t1: f64) -> Option<Aabb> {
+        // Curve stays inside the hull of its control points
+        let padding = Vec3::splat(self.max_width() / 2.0);
+
+        let (min, max) = self.points.iter().fold(
+            (Vec3::splat(f64::MAX), Vec3::splat(f64::MIN)),
+            |(min, max), &p| (Vec3::min(min, p), Vec3::max(max, p)),
+        );
+
+        Some(Aabb::new(min - padding, max + padding))
+    }
+}
+
+/// Orthonormal frame with the ray direction as Z
+struct Frame {
+    x: Vec3,
+    y: Vec3,
+    z: Vec3,
+    // Length of the ray direction, t * speed is the distance along Z
+    speed: f64,
+}
+
+impl Frame {
+    // X is picked perpendicular to the curve as well, If possible
+    fn new(ray: &Ray, along: Vec3) -> Self {
+        let speed = ray.direction.length();
+        let z = ray.direction / speed;
+
+        let x = z.cross(&along);
+        let x = if x.length() > 1e-12 {
+            x.unit_vector()
+        } else {
+            HitRecord::tangent_frame(z).0.unit_vector()
+        };
+
+        Self {
+            x,
+            y: z.cross(&x),
+            z,
+            speed,
+        }
+    }
+
+    fn to_ray_space(&self, v: Vec3) -> Vec3 {
+        Vec3::new(v.dot(&self.x), v.dot(&self.y), v.dot(&self.z))
+    }
+
+    fn to_world(&self, v: Vec3) -> Vec3 {
+        self.x * v.x() + self.y * v.y() + self.z * v.z()
+    }
+}
+
+/// Point & tangent of the curve at u
+fn evaluate(points: &[Vec3; 4], u: f64) -> (Vec3, Vec3) {
+    let lerp = |a: Vec3, b: Vec3| a * (1.0 - u) + b * u;
+
+    let [p0, p1, p2, p3] = *points;
+    let (a, b, c) = (lerp(p0, p1), lerp(p1, p2), lerp(p2, p3));
+    let (d, e) = (lerp(a, b), lerp(b, c));
+
+    (lerp(d, e), (e - d) * 3.0)
+}
+
+/// de Casteljau at u = 0.5, The two halves as curves of their own
+fn split(points: &[Vec3; 4]) -> ([Vec3; 4], [Vec3; 4]) {
+    let [p0, p1, p2, p3] = *points;
+    let (a, b, c) = ((p0 + p1) / 2.0, (p1 + p2) / 2.0, (p2 + p3) / 2.0);
+    let (d, e) = ((a + b) / 2.0, (b + c) / 2.0);
+    let middle = (d + e) / 2.0;
+
+    ([p0, a, d, middle], [middle, e, c, p3])
+}
+
+/// Point on the curve from blossoming the control values with t, t = [u, u, u] is the
+/// point at u & the other combinations give the control points of pieces of the curve
+fn blossom<P: Copy + Add<Output = P> + Mul<f64, Output = P>>(values: [P; 4], t: [f64; 3]) -> P {
+    let lerp = |a: P, b: P, t: f64| a * (1.0 - t) + b * t;
+
+    let [a, b, c] = [
+        lerp(values[0], values[1], t[0]),
+        lerp(values[1], values[2], t[0]),
+        lerp(values[2], values[3], t[0]),
+    ];
+    let [d, e] = [lerp(a, b, t[1]), lerp(b, c, t[1])];
+
+    lerp(d, e, t[2])
+}
@@ -0,0 +1,114 @@
+use crate::{
+    hitable::{
+        shapes::{
+            polynomial::solve_quadratic,
+            quadric::{around_axis, azimuth, nearest, sweep_radians, Cap},
+        },
+        HitRecord, Hitable,
+    },
+    types::{Ray, Vec3},
+    Aabb, Material,
+};
+
+/// Cylinder around the Y axis going from y_min to y_max, Open at both ends unless capped
+/// Put it anywhere else with a `Transform`
+#[derive(Clone)]
+pub struct Cylinder<T: Material + Clone + Sized> {
+    radius: f64,
+    y_min: f64,
+    y_max: f64,
+    phi_max: f64,
+    capped: bool,
+    material: T,
+}
+
+impl<T: Material + Clone + Sized> Cylinder<T> {
+    pub fn new(radius: f64, y_min: f64, y_max: f64, material: T) -> Self {
+        Self {
+            radius,
+            y_min: y_min.min(y_max),
+            y_max: y_max.max(y_min),
+            phi_max: sweep_radians(360.0),
+            capped: false,
+            material,
+        }
+    }
+
+    /// Only keeps the part from +X up to degrees around the axis, Towards +Z
+    pub fn with_sweep(mut self, degrees: f64) -> Self {
+        self.phi_max = sweep_radians(degrees);
+        self
+    }
+
+    /// Closes off both ends with flat caps
+    pub fn capped(mut self) -> Self {
+        self.capped = true;
+        self
+    }
+
+    fn caps(&self) -> [Cap; 2] {
+        [
+            Cap {
+                y: self.y_min,
+                radius: self.radius,
+                facing: -1.0,
+            },
+            Cap {
+                y: self.y_max,
+                radius: self.radius,
+                facing: 1.0,
+            },
+        ]
+    }
+}
+
+impl<T: Material + Clone + Sized> Hitable for Cylinder<T> {
+    fn hit(&self, ray: &Ray, t_min: f64, t_max: f64) -> Option<HitRecord> {
+        let (o, d) = (ray.origin, ray.direction);
+
+        let a = d.x() * d.x() + d.z() * d.z();
+        let b = 2.0 * (o.x() * d.x() + o.z() * d.z());
+        let c = o.x() * o.x() + o.z() * o.z() - self.radius * self.radius;
+
+        let side = nearest(solve_quadratic(a, b, c), t_min, t_max, |t| {
+            let p = ray.point_at_parameter(t);
+            p.y() >= self.y_min && p.y() <= self.y_max && azimuth(p) <= self.phi_max
+        });
+
+        let t_max = side.unwrap_or(t_max);
+        if self.capped {
+            let caps = self.caps();
+            let cap = caps
+                .iter()
+                .filter_map(|cap| Some((cap.hit(ray, t_min, t_max, self.phi_max)?, cap)))
+                .min_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+
+            if let Some((t, cap)) = cap {
+                return Some(cap.record(ray, t, self.phi_max, &self.material));
+            }
+        }
+
+        let t = side?;
+        let p = ray.point_at_parameter(t);
+        let normal = Vec3::new(p.x(), 0.0, p.z()) / self.radius;
+        let uv = (
+            azimuth(p) / self.phi_max,
+            (p.y() - self.y_min) / (self.y_max - self.y_min),
+        );
+
+        let mut hit_rec = HitRecord::new(t, p, normal, &self.material, uv).with_tangents(
+            around_axis(p, self.phi_max),
+            Vec3::new(0.0, self.y_max - self.y_min, 0.0),
+        );
+        hit_rec.set_face_normal(ray);
+
+        Some(hit_rec)
+    }
+
+    fn bounding_box(&self, _t0: f64, _t1: f64) -> Option<Aabb> {
+        Some(Aabb::new(
+            Vec3::new(-self.radius, self.y_min, -self.radius),
+            Vec3::new(self.radius, self.y_max, self.radius),
+        ))
+    }
+}
@@ -0,0 +1,118 @@
+use std::f64::consts::PI;
+
+use rand::{prelude::SmallRng, Rng};
+
+use crate::{
+    hitable::{
+        shapes::{planar_box, Sampleable},
+        HitRecord, Hitable,
+    },
+    types::{Ray, Vec3},
+    Aabb, Material,
+};
+
+/// Flat circle facing normal
+///
+/// u goes around the disk & v from the center out to the edge
+#[derive(Clone)]
+pub struct Disk<T: Material + Clone + Sized> {
+    center: Vec3,
+    normal: Vec3,
+    radius: f64,
+    // Two directions in the plane of the disk, u = 0 is along tangent
+    tangent: Vec3,
+    bitangent: Vec3,
+    material: T,
+}
+
+impl<T: Material + Clone + Sized> Disk<T> {
+    pub fn new(center: Vec3, normal: Vec3, radius: f64, material: T) -> Self {
+        let normal = normal.unit_vector();
+        let (tangent, bitangent) = HitRecord::tangent_frame(normal);
+
+        Self {
+            center,
+            normal,
+            radius,
+            tangent,
+            bitangent,
+            material,
+        }
+    }
+}
+
+impl<T: Material + Clone + Sized> Hitable for Disk<T> {
+    fn hit(&self, ray: &Ray, t_min: f64, t_max: f64) -> Option<HitRecord> {
+        let denominator = self.normal.dot(&ray.direction);
+
+        // Ray is parallel to the plane
+        if denominator.abs() < 1e-8 {
+            return None;
+        }
+
+        let t = self.normal.dot(&(self.center - ray.origin)) / denominator;
+        if t < t_min || t > t_max {
+            return None;
+        }
+
+        let p = ray.point_at_parameter(t);
+        let offset = p - self.center;
+        let distance = offset.length();
+
+        if distance > self.radius {
+            return None;
+        }
+
+        let (x, y) = (offset.dot(&self.tangent), offset.dot(&self.bitangent));
+        let phi = y.atan2(x).rem_euclid(2.0 * PI);
+
+        let u = phi / (2.0 * PI);
+        let v = distance / self.radius;
+
+        // Around the circle & straight out from the center, Both collapse at the center
+        let (dpdu, dpdv) = if distance > 1e-8 {
+            let radial = offset / distance;
+            (
+                self.normal.cross(&radial) * (2.0 * PI * distance),
+                radial * self.radius,
+            )
+        } else {
+            (self.tangent, self.bitangent)
+        };
+
+        let mut hit_rec =
+            HitRecord::new(t, p, self.normal, &self.material, (u, v)).with_tangents(dpdu, dpdv);
+
+        hit_rec.set_face_normal(ray);
+
+        Some(hit_rec)
+    }
+
+    fn bounding_box(&self, _t0: f64, _t1: f64) -> Option<Aabb> {
+        // Extent of a circle along each axis is radius * sin of the angle between normal & axis
+        let n = self.normal;
+        let extent = Vec3::new(
+            (1.0 - n.x() * n.x()).max(0.0).sqrt(),
+            (1.0 - n.y() * n.y()).max(0.0).sqrt(),
+            (1.0 - n.z() * n.z()).max(0.0).sqrt(),
+        ) * self.radius;
+
+        Some(planar_box(&[self.center - extent, self.center + extent]))
+    }
+}
+
+impl<T: Material + Clone + Sized> Sampleable for Disk<T> {
+    fn area(&self) -> f64 {
+        PI * self.radius * self.radius
+    }
+
+    fn sample(&self, rng: &mut SmallRng) -> (Vec3, Vec3) {
+        // sqrt so that the points aren't bunched up around the center
+        let r = self.radius * rng.gen::<f64>().sqrt();
+        let phi = 2.0 * PI * rng.gen::<f64>();
+
+        let p = self.center + self.tangent * (r * phi.cos()) + self.bitangent * (r * phi.sin());
+
+        (p, self.normal)
+    }
+}
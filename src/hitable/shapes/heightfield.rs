@@ -0,0 +1,260 @@
+use image::error::ImageError;
+
+use crate::{
+    hitable::{shapes::intersect_triangle, HitRecord, Hitable},
+    texture::{nodes::luminance, ImageTexture},
+    types::{Ray, Vec3},
+    Aabb, Material, Texture,
+};
+
+/// Terrain made of a grid of heights, Each cell split into two triangles
+///
+/// Covers [0, size.x] along X & [0, size.z] along Z with heights scaled by size.y.
+/// u goes along +X & v along -Z over the whole terrain, So an image seen from above with
+/// X to the right isn't mirrored & a texture of the same image lines up with the heights.
+/// Rays walk the grid cell by cell instead of going through a BVH of millions of triangles
+pub struct Heightfield<T: Material + Clone + Sized> {
+    // Number of samples along X & Z, One more than the number of cells
+    nx: usize,
+    nz: usize,
+    size: Vec3,
+    // Scaled heights, Row by row along X
+    heights: Vec<f64>,
+    normals: Vec<Vec3>,
+    // Lowest & highest corner of each cell, Lets rays skip cells they pass over
+    cell_ranges: Vec<(f64, f64)>,
+    min_height: f64,
+    max_height: f64,
+    material: T,
+}
+
+impl<T: Material + Clone + Sized> Heightfield<T> {
+    /// heights has nx * nz values, Row by row along X
+    pub fn new(nx: usize, nz: usize, heights: &[f64], size: Vec3, material: T) -> Self {
+        assert!(nx >= 2 && nz >= 2, "heightfield needs at least 2x2 samples");
+        assert_eq!(heights.len(), nx * nz);
+
+        let heights = heights.iter().map(|h| h * size.y()).collect::<Vec<_>>();
+
+        let (dx, dz) = (size.x() / (nx - 1) as f64, size.z() / (nz - 1) as f64);
+        let at = |i: usize, j: usize| heights[j * nx + i];
+
+        // Central differences, One sided on the edges
+        let mut normals = Vec::with_capacity(nx * nz);
+        for j in 0..nz {
+            for i in 0..nx {
+                let (i0, i1) = (i.saturating_sub(1), (i + 1).min(nx - 1));
+                let (j0, j1) = (j.saturating_sub(1), (j + 1).min(nz - 1));
+
+                let slope_x = (at(i1, j) - at(i0, j)) / ((i1 - i0) as f64 * dx);
+                let slope_z = (at(i, j1) - at(i, j0)) / ((j1 - j0) as f64 * dz);
+
+                normals.push(Vec3::new(-slope_x, 1.0, -slope_z).unit_vector());
+            }
+        }
+
+        let mut cell_ranges = Vec::with_capacity((nx - 1) * (nz - 1));
+        for j in 0..nz - 1 {
+            for i in 0..nx - 1 {
+                let corners = [at(i, j), at(i + 1, j), at(i, j + 1), at(i + 1, j + 1)];
+
+                cell_ranges.push(
+                    corners
+                        .iter()
+                        .fold((f64::MAX, f64::MIN), |(lo, hi), &h| (lo.min(h), hi.max(h))),
+                );
+            }
+        }
+
+        let (min_height, max_height) = cell_ranges
+            .iter()
+            .fold((f64::MAX, f64::MIN), |(lo, hi), &(l, h)| {
+                (lo.min(l), hi.max(h))
+            });
+
+        Self {
+            nx,
+            nz,
+            size,
+            heights,
+            normals,
+            cell_ranges,
+            min_height,
+            max_height,
+            material,
+        }
+    }
+
+    /// Samples the brightness of a texture on a nx by nz grid over its uv square.
+    /// p passed to the texture is the point on the flat ground, So 3D textures like
+    /// noise work too
+    pub fn from_texture(
+        texture: &dyn Texture,
+        nx: usize,
+        nz: usize,
+        size: Vec3,
+        material: T,
+    ) -> Self {
+        let mut heights = Vec::with_capacity(nx * nz);
+        for j in 0..nz {
+            let z = j as f64 / (nz - 1) as f64;
+            for i in 0..nx {
+                let u = i as f64 / (nx - 1) as f64;
+                let p = Vec3::new(u * size.x(), 0.0, z * size.z());
+                let v = 1.0 - z;
+
+                heights.push(luminance(texture.value(u, v, p)));
+            }
+        }
+
+        Self::new(nx, nz, &heights, size, material)
+    }
+
+    /// Grayscale height map, One sample per pixel. Read as data, So without sRGB decoding
+    pub fn from_image(filename: &str, size: Vec3, material: T) -> Result<Self, ImageError> {
+        let image = ImageTexture::from_filename_linear(filename)?;
+        let (width, height) = image.dimensions();
+
+        Ok(Self::from_texture(&image, width, height, size, material))
+    }
+
+    fn vertex(&self, i: usize, j: usize) -> (Vec3, Vec3) {
+        let (dx, dz) = self.spacing();
+        let index = j * self.nx + i;
+
+        (
+            Vec3::new(i as f64 * dx, self.heights[index], j as f64 * dz),
+            self.normals[index],
+        )
+    }
+
+    fn spacing(&self) -> (f64, f64) {
+        (
+            self.size.x() / (self.nx - 1) as f64,
+            self.size.z() / (self.nz - 1) as f64,
+        )
+    }
+
+    /// Nearest hit with the two triangles of cell (i, j)
+    fn hit_cell(&self, ray: &Ray, i: usize, j: usize, t_min: f64, t_max: f64) -> Option<HitRecord> {
+        let corners = [
+            self.vertex(i, j),
+            self.vertex(i + 1, j),
+            self.vertex(i + 1, j + 1),
+            self.vertex(i, j + 1),
+        ];
+
+        let (t, (a, b, c), (b1, b2)) = [(0, 1, 2), (0, 2, 3)]
+            .iter()
+            .filter_map(|&(a, b, c)| {
+                let (t, b1, b2) = intersect_triangle(
+                    ray,
+                    corners[a].0,
+                    corners[b].0 - corners[a].0,
+                    corners[c].0 - corners[a].0,
+                )?;
+                Some((t, (a, b, c), (b1, b2)))
+            })
+            .filter(|&(t, _, _)| t > t_min && t < t_max)
+            .min_by(|x, y| x.0.partial_cmp(&y.0).unwrap())?;
+
+        let b0 = 1.0 - b1 - b2;
+        let normal = (corners[a].1 * b0 + corners[b].1 * b1 + corners[c].1 * b2).unit_vector();
+
+        let p = ray.point_at_parameter(t);
+        let uv = (p.x() / self.size.x(), 1.0 - p.z() / self.size.z());
+
+        // Moving along u or v follows the slope of the surface
+        let dpdu = Vec3::new(self.size.x(), -normal.x() / normal.y() * self.size.x(), 0.0);
+        let dpdv = Vec3::new(0.0, normal.z() / normal.y() * self.size.z(), -self.size.z());
+
+        let mut hit_rec =
+            HitRecord::new(t, p, normal, &self.material, uv).with_tangents(dpdu, dpdv);
+        hit_rec.set_face_normal(ray);
+
+        Some(hit_rec)
+    }
+}
+
+impl<T: Material + Clone + Sized> Hitable for Heightfield<T> {
+    fn hit(&self, ray: &Ray, t_min: f64, t_max: f64) -> Option<HitRecord> {
+        let (start, end) = self.bounding_box(0.0, 0.0)?.clip(ray, t_min, t_max)?;
+        let (dx, dz) = self.spacing();
+        let (cells_x, cells_z) = (self.nx - 1, self.nz - 1);
+
+        let entry = ray.point_at_parameter(start);
+        let cell =
+            |x: f64, d: f64, count: usize| ((x / d).floor().max(0.0) as usize).min(count - 1);
+        let (mut i, mut j) = (cell(entry.x(), dx, cells_x), cell(entry.z(), dz, cells_z));
+
+        // 2D DDA, t of the next cell boundary along X & Z and how much t it takes to cross a cell
+        let axis = |origin: f64, direction: f64, index: usize, d: f64| {
+            if direction > 0.0 {
+                (
+                    ((index + 1) as f64 * d - origin) / direction,
+                    d / direction,
+                    1,
+                )
+            } else if direction < 0.0 {
+                ((index as f64 * d - origin) / direction, -d / direction, -1)
+            } else {
+                (f64::MAX, f64::MAX, 0)
+            }
+        };
+        let (mut next_x, delta_x, step_x) = axis(ray.origin.x(), ray.direction.x(), i, dx);
+        let (mut next_z, delta_z, step_z) = axis(ray.origin.z(), ray.direction.z(), j, dz);
+
+        let mut t_enter = start;
+        loop {
+            let t_exit = next_x.min(next_z).min(end);
+
+            // Only look at the triangles if the ray's height over the cell overlaps them
+            let (low, high) = self.cell_ranges[j * cells_x + i];
+            let (y0, y1) = (
+                ray.point_at_parameter(t_enter).y(),
+                ray.point_at_parameter(t_exit).y(),
+            );
+            if y0.min(y1) <= high && y0.max(y1) >= low {
+                // A little slack so hits right on a cell boundary aren't lost
+                let slack = 1e-9 * (1.0 + t_exit.abs());
+                if let Some(hit_rec) = self.hit_cell(
+                    ray,
+                    i,
+                    j,
+                    (t_enter - slack).max(t_min),
+                    (t_exit + slack).min(t_max),
+                ) {
+                    return Some(hit_rec);
+                }
+            }
+
+            if t_exit >= end {
+                return None;
+            }
+
+            if next_x < next_z {
+                if (step_x < 0 && i == 0) || (step_x > 0 && i == cells_x - 1) {
+                    return None;
+                }
+                i = (i as i64 + step_x) as usize;
+                next_x += delta_x;
+            } else {
+                if (step_z < 0 && j == 0) || (step_z > 0 && j == cells_z - 1) {
+                    return None;
+                }
+                j = (j as i64 + step_z) as usize;
+                next_z += delta_z;
+            }
+
+            t_enter = t_exit;
+        }
+    }
+
+    fn bounding_box(&self, _t0: f64, _t1: f64) -> Option<Aabb> {
+        // Padded so that a completely flat terrain still has some thickness
+        Some(Aabb::new(
+            Vec3::new(0.0, self.min_height - 0.0001, 0.0),
+            Vec3::new(self.size.x(), self.max_height + 0.0001, self.size.z()),
+        ))
+    }
+}
@@ -1,9 +1,151 @@
+mod cone;
 mod cuboid;
+mod curve;
+mod cylinder;
+mod disk;
+mod heightfield;
 mod moving_sphere;
+mod paraboloid;
+mod polynomial;
+mod quad;
+mod quadric;
 mod rectangle;
 mod sphere;
+mod torus;
+mod triangle;
 
+pub use cone::Cone;
 pub use cuboid::Cuboid;
+pub use curve::Curve;
+pub use cylinder::Cylinder;
+pub use disk::Disk;
+pub use heightfield::Heightfield;
 pub use moving_sphere::MovingSphere;
+pub use paraboloid::Paraboloid;
+pub use quad::Quad;
 pub use rectangle::RectBuilder;
 pub use sphere::Sphere;
+pub use torus::Torus;
+pub use triangle::Triangle;
+
+pub(crate) use triangle::{intersect_triangle, uv_tangents};
+
+use rand::prelude::SmallRng;
+
+use crate::{
+    hitable::Hitable,
+    types::{Ray, Vec3},
+    Aabb,
+};
+
+/// Shapes whose surface can be sampled uniformly, e.g. to send rays straight at an area light
+pub trait Sampleable: Hitable {
+    fn area(&self) -> f64;
+
+    /// Uniformly distributed point on the surface & the unit normal there
+    fn sample(&self, rng: &mut SmallRng) -> (Vec3, Vec3);
+
+    /// Probability density over solid angle of the direction from origin to the points
+    /// picked by `sample`, 0.0 if direction misses the shape
+    fn pdf(&self, origin: Vec3, direction: Vec3) -> f64 {
+        let direction = direction.unit_vector();
+
+        match self.hit(&Ray::new(origin, direction, 0.0), 0.001, f64::MAX) {
+            Some(hit) => {
+                let cosine = direction.dot(&hit.normal).abs();
+                hit.t * hit.t / (cosine * self.area())
+            }
+            None => 0.0,
+        }
+    }
+}
+
+pub trait ParallelSample: Sampleable + Send + Sync {}
+impl<T: Sampleable + Send + Sync> ParallelSample for T {}
+
+// Box around a flat shape, Padded a little so that it doesn't have zero thickness
+// when the shape lines up with one of the axes
+fn planar_box(points: &[Vec3]) -> Aabb {
+    let padding = Vec3::splat(0.0001);
+
+    let (min, max) = points.iter().fold(
+        (Vec3::splat(f64::MAX), Vec3::splat(f64::MIN)),
+        |(min, max), &p| (Vec3::min(min, p), Vec3::max(max, p)),
+    );
+
+    Aabb::new(min - padding, max + padding)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use rand::{rngs::SmallRng, Rng, SeedableRng};
+
+    use super::{Disk, Quad, Sampleable, Triangle};
+    use crate::{materials::Lambertian, texture::Solid, types::Vec3};
+
+    // Solid angle the shape covers as seen from origin, Estimated once from the points
+    // picked by `sample` & once by shooting uniformly distributed directions at it
+    fn solid_angles(shape: &impl Sampleable, origin: Vec3) -> (f64, f64) {
+        let mut rng = SmallRng::seed_from_u64(7);
+        let n = 200_000;
+
+        let from_samples = (0..n)
+            .map(|_| {
+                let (point, _) = shape.sample(&mut rng);
+                1.0 / shape.pdf(origin, point - origin)
+            })
+            .sum::<f64>()
+            / n as f64;
+
+        let hits = (0..n)
+            .filter(|_| {
+                let z: f64 = rng.gen_range(-1.0..=1.0);
+                let phi = rng.gen_range(0.0..2.0 * PI);
+                let r = (1.0 - z * z).sqrt();
+                let direction = Vec3::new(r * phi.cos(), r * phi.sin(), z);
+
+                shape.pdf(origin, direction) > 0.0
+            })
+            .count();
+
+        (from_samples, 4.0 * PI * hits as f64 / n as f64)
+    }
+
+    #[test]
+    fn sample_pdf_matches_covered_solid_angle() {
+        let material = Lambertian::new(Solid::new(Vec3::splat(0.5)));
+        let origin = Vec3::new(0.3, -0.2, 0.5);
+
+        let quad = Quad::new(
+            Vec3::new(-1.0, 1.0, -1.0),
+            Vec3::new(2.0, 0.5, 0.0),
+            Vec3::new(0.0, 0.3, 1.5),
+            material.clone(),
+        );
+        let disk = Disk::new(
+            Vec3::new(0.5, 0.0, -1.5),
+            Vec3::new(0.2, 0.1, 1.0),
+            1.0,
+            material.clone(),
+        );
+        let triangle = Triangle::new(
+            Vec3::new(-1.0, -1.0, 2.0),
+            Vec3::new(1.5, -0.5, 2.0),
+            Vec3::new(0.0, 1.0, 2.5),
+            material,
+        );
+
+        for (from_samples, from_directions) in [
+            solid_angles(&quad, origin),
+            solid_angles(&disk, origin),
+            solid_angles(&triangle, origin),
+        ]
+        .iter()
+        {
+            assert!(*from_directions > 0.1);
+            assert!((from_samples - from_directions).abs() < 0.03 * from_directions);
+        }
+    }
+}
@@ -1,10 +1,10 @@
 use crate::{
-    hitable::{HitRecord, Hitable},
+    hitable::{shapes::Sphere, HitRecord, Hitable},
     types::{Ray, Vec3},
     Aabb, Material,
 };
 
-pub struct MovingSphere<T: Material + Sized> {
+pub struct MovingSphere<T: Material + Clone + Sized> {
     radius: f64,
     center_start: Vec3,
     center_end: Vec3,
@@ -13,7 +13,7 @@ pub struct MovingSphere<T: Material + Sized> {
     material: T,
 }
 
-impl<T: Material + Sized> MovingSphere<T> {
+impl<T: Material + Clone + Sized> MovingSphere<T> {
     pub fn new(
         center_start: Vec3,
         center_end: Vec3,
@@ -52,7 +52,7 @@ impl<T: Material + Sized> MovingSphere<T> {
     }
 }
 
-impl<T: Material + Sized> Hitable for MovingSphere<T> {
+impl<T: Material + Clone + Sized> Hitable for MovingSphere<T> {
     fn hit(&self, ray: &Ray, t_min: f64, t_max: f64) -> Option<HitRecord> {
         let oc = ray.origin - self.center(ray.time());
         let a = ray.direction.dot(&ray.direction);
@@ -71,8 +71,14 @@ impl<T: Material + Sized> Hitable for MovingSphere<T> {
                 let p = ray.point_at_parameter(root);
                 let normal = (p - self.center(ray.time())) / self.radius;
 
+                let (dpdu, dpdv) = Sphere::<T>::get_tangents(normal, self.radius);
+
                 let mut hit_rec =
-                    HitRecord::new(root, p, normal, &self.material, Self::get_uv(normal));
+                    HitRecord::new(root, p, normal, &self.material, Self::get_uv(normal))
+                        .with_tangents(dpdu, dpdv);
+
+                // Where the point was at the start, So that textures move with the sphere
+                hit_rec.local_p = p - self.center(ray.time()) + self.center_start;
 
                 hit_rec.set_face_normal(ray);
 
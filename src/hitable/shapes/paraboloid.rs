@@ -0,0 +1,118 @@
+use crate::{
+    hitable::{
+        shapes::{
+            polynomial::solve_quadratic,
+            quadric::{around_axis, azimuth, nearest, sweep_radians, Cap},
+        },
+        HitRecord, Hitable,
+    },
+    types::{Ray, Vec3},
+    Aabb, Material,
+};
+
+/// Bowl around the Y axis, y = y_max * (x^2 + z^2) / radius^2 cut off between y_min & y_max
+/// So it's radius wide at the top. Open at the top unless capped, Put it anywhere else
+/// with a `Transform`
+#[derive(Clone)]
+pub struct Paraboloid<T: Material + Clone + Sized> {
+    radius: f64,
+    y_min: f64,
+    y_max: f64,
+    phi_max: f64,
+    capped: bool,
+    material: T,
+}
+
+impl<T: Material + Clone + Sized> Paraboloid<T> {
+    pub fn new(radius: f64, y_min: f64, y_max: f64, material: T) -> Self {
+        let y_max = y_max.max(1e-6);
+
+        Self {
+            radius,
+            y_min: y_min.clamp(0.0, y_max),
+            y_max,
+            phi_max: sweep_radians(360.0),
+            capped: false,
+            material,
+        }
+    }
+
+    /// Only keeps the part from +X up to degrees around the axis, Towards +Z
+    pub fn with_sweep(mut self, degrees: f64) -> Self {
+        self.phi_max = sweep_radians(degrees);
+        self
+    }
+
+    /// Closes off the top with a flat cap
+    #[allow(dead_code)]
+    pub fn capped(mut self) -> Self {
+        self.capped = true;
+        self
+    }
+
+    fn top(&self) -> Cap {
+        Cap {
+            y: self.y_max,
+            radius: self.radius,
+            facing: 1.0,
+        }
+    }
+}
+
+impl<T: Material + Clone + Sized> Hitable for Paraboloid<T> {
+    fn hit(&self, ray: &Ray, t_min: f64, t_max: f64) -> Option<HitRecord> {
+        let (o, d) = (ray.origin, ray.direction);
+        let k = self.y_max / (self.radius * self.radius);
+
+        let a = k * (d.x() * d.x() + d.z() * d.z());
+        let b = 2.0 * k * (o.x() * d.x() + o.z() * d.z()) - d.y();
+        let c = k * (o.x() * o.x() + o.z() * o.z()) - o.y();
+
+        let side = nearest(solve_quadratic(a, b, c), t_min, t_max, |t| {
+            let p = ray.point_at_parameter(t);
+            p.y() >= self.y_min && p.y() <= self.y_max && azimuth(p) <= self.phi_max
+        });
+
+        let t_max = side.unwrap_or(t_max);
+        if self.capped {
+            let top = self.top();
+            if let Some(t) = top.hit(ray, t_min, t_max, self.phi_max) {
+                return Some(top.record(ray, t, self.phi_max, &self.material));
+            }
+        }
+
+        let t = side?;
+        let p = ray.point_at_parameter(t);
+
+        // Gradient of k * (x^2 + z^2) - y, Pointing away from the inside of the bowl
+        let normal = Vec3::new(2.0 * k * p.x(), -1.0, 2.0 * k * p.z()).unit_vector();
+        let uv = (
+            azimuth(p) / self.phi_max,
+            (p.y() - self.y_min) / (self.y_max - self.y_min).max(1e-12),
+        );
+
+        // Collapses at the very bottom of the bowl
+        let (dpdu, dpdv) = if p.y() > 1e-8 {
+            (
+                around_axis(p, self.phi_max),
+                Vec3::new(p.x() / (2.0 * p.y()), 1.0, p.z() / (2.0 * p.y()))
+                    * (self.y_max - self.y_min),
+            )
+        } else {
+            HitRecord::tangent_frame(normal)
+        };
+
+        let mut hit_rec =
+            HitRecord::new(t, p, normal, &self.material, uv).with_tangents(dpdu, dpdv);
+        hit_rec.set_face_normal(ray);
+
+        Some(hit_rec)
+    }
+
+    fn bounding_box(&self, _t0: f64, _t1: f64) -> Option<Aabb> {
+        Some(Aabb::new(
+            Vec3::new(-self.radius, self.y_min, -self.radius),
+            Vec3::new(self.radius, self.y_max, self.radius),
+        ))
+    }
+}
@@ -0,0 +1,120 @@
+//! Real roots of low degree polynomials for the ray/surface equations of the curved shapes
+
+use std::f64::consts::PI;
+
+const EPSILON: f64 = 1e-12;
+
+/// Roots of a*t^2 + b*t + c in increasing order, Also handles a = 0
+pub fn solve_quadratic(a: f64, b: f64, c: f64) -> Vec<f64> {
+    if a.abs() < EPSILON {
+        return if b.abs() < EPSILON {
+            vec![]
+        } else {
+            vec![-c / b]
+        };
+    }
+
+    let discriminant = b * b - 4.0 * a * c;
+    if discriminant < 0.0 {
+        return vec![];
+    }
+
+    // Avoids subtracting two nearly equal numbers when b is much larger than a * c
+    let q = -0.5 * (b + b.signum() * discriminant.sqrt());
+    let (t0, t1) = if q.abs() < EPSILON {
+        (0.0, 0.0)
+    } else {
+        (q / a, c / q)
+    };
+
+    if t0 < t1 {
+        vec![t0, t1]
+    } else {
+        vec![t1, t0]
+    }
+}
+
+/// Roots of t^3 + a*t^2 + b*t + c, In no particular order
+fn solve_normalized_cubic(a: f64, b: f64, c: f64) -> Vec<f64> {
+    // Substituting t = x - a / 3 gives x^3 + p*x + q
+    let p = b - a * a / 3.0;
+    let q = 2.0 * a * a * a / 27.0 - a * b / 3.0 + c;
+    let shift = -a / 3.0;
+
+    let discriminant = q * q / 4.0 + p * p * p / 27.0;
+
+    if discriminant.abs() < EPSILON {
+        if q.abs() < EPSILON {
+            vec![shift]
+        } else {
+            let u = (-q / 2.0).cbrt();
+            vec![2.0 * u + shift, -u + shift]
+        }
+    } else if discriminant > 0.0 {
+        let sqrt = discriminant.sqrt();
+        vec![(-q / 2.0 + sqrt).cbrt() + (-q / 2.0 - sqrt).cbrt() + shift]
+    } else {
+        // Three real roots, Trigonometric form avoids complex numbers
+        let r = (-p / 3.0).sqrt();
+        let phi = (-q / (2.0 * r * r * r)).clamp(-1.0, 1.0).acos() / 3.0;
+
+        (0..3)
+            .map(|k| 2.0 * r * (phi - 2.0 * PI * k as f64 / 3.0).cos() + shift)
+            .collect()
+    }
+}
+
+/// Real roots of c4*t^4 + c3*t^3 + c2*t^2 + c1*t + c0 in increasing order
+///
+/// Ferrari's method, Followed by a few steps of Newton's method on the original polynomial
+/// since the closed form loses quite a bit of precision
+pub fn solve_quartic(c4: f64, c3: f64, c2: f64, c1: f64, c0: f64) -> Vec<f64> {
+    let (a, b, c, d) = (c3 / c4, c2 / c4, c1 / c4, c0 / c4);
+
+    // Substituting t = x - a / 4 gives x^4 + p*x^2 + q*x + r
+    let a2 = a * a;
+    let p = b - 3.0 * a2 / 8.0;
+    let q = a2 * a / 8.0 - a * b / 2.0 + c;
+    let r = -3.0 * a2 * a2 / 256.0 + a2 * b / 16.0 - a * c / 4.0 + d;
+    let shift = -a / 4.0;
+
+    let mut roots = if q.abs() < EPSILON {
+        // Biquadratic, Quadratic in x^2
+        solve_quadratic(1.0, p, r)
+            .into_iter()
+            .filter(|&z| z >= 0.0)
+            .flat_map(|z| [z.sqrt(), -z.sqrt()])
+            .collect::<Vec<_>>()
+    } else {
+        // Any real root m of the resolvent cubic splits it into two quadratics
+        let m = solve_normalized_cubic(2.0 * p, p * p - 4.0 * r, -q * q)
+            .into_iter()
+            .fold(f64::MIN, f64::max);
+
+        if m <= 0.0 {
+            return vec![];
+        }
+
+        let s = m.sqrt();
+        let mut roots = solve_quadratic(1.0, s, (p + m) / 2.0 - q / (2.0 * s));
+        roots.extend(solve_quadratic(1.0, -s, (p + m) / 2.0 + q / (2.0 * s)));
+        roots
+    };
+
+    for root in roots.iter_mut() {
+        *root += shift;
+
+        for _ in 0..2 {
+            let t = *root;
+            let value = (((c4 * t + c3) * t + c2) * t + c1) * t + c0;
+            let derivative = ((4.0 * c4 * t + 3.0 * c3) * t + 2.0 * c2) * t + c1;
+
+            if derivative.abs() > EPSILON {
+                *root -= value / derivative;
+            }
+        }
+    }
+
+    roots.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    roots
+}
@@ -0,0 +1,93 @@
+use rand::{prelude::SmallRng, Rng};
+
+use crate::{
+    hitable::{
+        shapes::{planar_box, Sampleable},
+        HitRecord, Hitable,
+    },
+    types::{Ray, Vec3},
+    Aabb, Material,
+};
+
+/// Parallelogram with one corner at corner & sides along u and v
+///
+/// Front face is on the side of u x v, So with u & v going counter clockwise
+/// when looked at from the front
+#[derive(Clone)]
+pub struct Quad<T: Material + Clone + Sized> {
+    corner: Vec3,
+    u: Vec3,
+    v: Vec3,
+    normal: Vec3,
+    // n / (n . n) for the non normalized n, Turns a point on the plane into u & v coordinates
+    w: Vec3,
+    material: T,
+}
+
+impl<T: Material + Clone + Sized> Quad<T> {
+    pub fn new(corner: Vec3, u: Vec3, v: Vec3, material: T) -> Self {
+        let n = u.cross(&v);
+
+        Self {
+            corner,
+            u,
+            v,
+            normal: n.unit_vector(),
+            w: n / n.dot(&n),
+            material,
+        }
+    }
+}
+
+impl<T: Material + Clone + Sized> Hitable for Quad<T> {
+    fn hit(&self, ray: &Ray, t_min: f64, t_max: f64) -> Option<HitRecord> {
+        let denominator = self.normal.dot(&ray.direction);
+
+        // Ray is parallel to the plane
+        if denominator.abs() < 1e-8 {
+            return None;
+        }
+
+        let t = self.normal.dot(&(self.corner - ray.origin)) / denominator;
+        if t < t_min || t > t_max {
+            return None;
+        }
+
+        let p = ray.point_at_parameter(t);
+        let q = p - self.corner;
+        let alpha = self.w.dot(&q.cross(&self.v));
+        let beta = self.w.dot(&self.u.cross(&q));
+
+        if !(0.0..=1.0).contains(&alpha) || !(0.0..=1.0).contains(&beta) {
+            return None;
+        }
+
+        let mut hit_rec = HitRecord::new(t, p, self.normal, &self.material, (alpha, beta))
+            .with_tangents(self.u, self.v);
+
+        hit_rec.set_face_normal(ray);
+
+        Some(hit_rec)
+    }
+
+    fn bounding_box(&self, _t0: f64, _t1: f64) -> Option<Aabb> {
+        Some(planar_box(&[
+            self.corner,
+            self.corner + self.u,
+            self.corner + self.v,
+            self.corner + self.u + self.v,
+        ]))
+    }
+}
+
+impl<T: Material + Clone + Sized> Sampleable for Quad<T> {
+    fn area(&self) -> f64 {
+        self.u.cross(&self.v).length()
+    }
+
+    fn sample(&self, rng: &mut SmallRng) -> (Vec3, Vec3) {
+        let p = self.corner + self.u * rng.gen::<f64>() + self.v * rng.gen::<f64>();
+
+        (p, self.normal)
+    }
+}
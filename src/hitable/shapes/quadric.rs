@@ -0,0 +1,94 @@
+//! Bits shared by the shapes of revolution around the Y axis, i.e. the cylinder, cone,
+//! paraboloid & torus. u always goes around the axis, Starting from +X towards +Z
+
+use std::f64::consts::PI;
+
+use crate::{
+    hitable::HitRecord,
+    types::{Ray, Vec3},
+    Material,
+};
+
+/// Angle of p around the Y axis in [0, 2pi)
+pub fn azimuth(p: Vec3) -> f64 {
+    p.z().atan2(p.x()).rem_euclid(2.0 * PI)
+}
+
+/// Checks the sweep in degrees & turns it into radians
+pub fn sweep_radians(degrees: f64) -> f64 {
+    degrees.clamp(0.0, 360.0).to_radians()
+}
+
+/// dp/du of a point p on a surface of revolution with u = phi / phi_max
+pub fn around_axis(p: Vec3, phi_max: f64) -> Vec3 {
+    Vec3::new(-p.z(), 0.0, p.x()) * phi_max
+}
+
+/// Nearest root in (t_min, t_max) for which accept returns true
+pub fn nearest(
+    roots: Vec<f64>,
+    t_min: f64,
+    t_max: f64,
+    accept: impl Fn(f64) -> bool,
+) -> Option<f64> {
+    roots
+        .into_iter()
+        .find(|&t| t > t_min && t < t_max && accept(t))
+}
+
+/// Flat round cap at height y closing off a shape of revolution
+pub struct Cap {
+    pub y: f64,
+    pub radius: f64,
+    // +1.0 if the cap faces up, -1.0 if down
+    pub facing: f64,
+}
+
+impl Cap {
+    pub fn hit(&self, ray: &Ray, t_min: f64, t_max: f64, phi_max: f64) -> Option<f64> {
+        if ray.direction.y().abs() < 1e-12 {
+            return None;
+        }
+
+        let t = (self.y - ray.origin.y()) / ray.direction.y();
+        if t <= t_min || t >= t_max {
+            return None;
+        }
+
+        let p = ray.point_at_parameter(t);
+        if p.x() * p.x() + p.z() * p.z() > self.radius * self.radius || azimuth(p) > phi_max {
+            return None;
+        }
+
+        Some(t)
+    }
+
+    /// u goes around like on the side, v from the center to the rim
+    pub fn record<'a>(
+        &self,
+        ray: &Ray,
+        t: f64,
+        phi_max: f64,
+        material: &'a dyn Material,
+    ) -> HitRecord<'a> {
+        let p = ray.point_at_parameter(t);
+        let distance = (p.x() * p.x() + p.z() * p.z()).sqrt();
+
+        let normal = Vec3::new(0.0, self.facing, 0.0);
+        let uv = (azimuth(p) / phi_max, distance / self.radius);
+
+        let (dpdu, dpdv) = if distance > 1e-8 {
+            (
+                around_axis(p, phi_max),
+                Vec3::new(p.x(), 0.0, p.z()) * (self.radius / distance),
+            )
+        } else {
+            HitRecord::tangent_frame(normal)
+        };
+
+        let mut hit_rec = HitRecord::new(t, p, normal, material, uv).with_tangents(dpdu, dpdv);
+        hit_rec.set_face_normal(ray);
+
+        hit_rec
+    }
+}
@@ -41,13 +41,17 @@ where
         let u = (d1 - self.d1_range.start()) / (self.d1_range.end() - self.d1_range.start());
         let v = (d2 - self.d2_range.start()) / (self.d2_range.end() - self.d2_range.start());
 
+        let dpdu = Vec3::splat(0.0).set::<D1>(self.d1_range.end() - self.d1_range.start());
+        let dpdv = Vec3::splat(0.0).set::<D2>(self.d2_range.end() - self.d2_range.start());
+
         let mut hit_rec = HitRecord::new(
             t,
             ray.point_at_parameter(t),
             Vec3::splat(0.0).set::<D3>(1.0),
             &self.material,
             (u, v),
-        );
+        )
+        .with_tangents(dpdu, dpdv);
 
         hit_rec.set_face_normal(ray);
 
@@ -32,6 +32,26 @@ impl<T: Material + Clone + Sized> Sphere<T> {
 
         (u, v)
     }
+
+    /// Partial derivatives of a point on the sphere with respect to u & v
+    /// p is the unit outward normal at that point, Same as in `get_uv`
+    pub fn get_tangents(p: Vec3, radius: f64) -> (Vec3, Vec3) {
+        let sin_theta = (p.x() * p.x() + p.z() * p.z()).sqrt();
+
+        // At the poles, dp/du collapses so we fallback to any tangent frame
+        if sin_theta < 1e-8 {
+            return HitRecord::tangent_frame(p);
+        }
+
+        let dpdu = Vec3::new(p.z(), 0.0, -p.x()) * (2.0 * std::f64::consts::PI * radius);
+        let dpdv = Vec3::new(
+            -p.x() * p.y() / sin_theta,
+            sin_theta,
+            -p.y() * p.z() / sin_theta,
+        ) * (std::f64::consts::PI * radius);
+
+        (dpdu, dpdv)
+    }
 }
 
 impl<T: Material + Clone + Sized> Hitable for Sphere<T> {
@@ -59,8 +79,11 @@ impl<T: Material + Clone + Sized> Hitable for Sphere<T> {
                 let p = ray.point_at_parameter(root);
                 let normal = (p - self.center) / self.radius;
 
+                let (dpdu, dpdv) = Self::get_tangents(normal, self.radius);
+
                 let mut hit_rec =
-                    HitRecord::new(root, p, normal, &self.material, Self::get_uv(normal));
+                    HitRecord::new(root, p, normal, &self.material, Self::get_uv(normal))
+                        .with_tangents(dpdu, dpdv);
 
                 hit_rec.set_face_normal(ray);
 
@@ -0,0 +1,99 @@
+use std::f64::consts::PI;
+
+use crate::{
+    hitable::{
+        shapes::{
+            polynomial::solve_quartic,
+            quadric::{around_axis, azimuth, nearest, sweep_radians},
+        },
+        HitRecord, Hitable,
+    },
+    types::{Ray, Vec3},
+    Aabb, Material,
+};
+
+/// Ring around the Y axis, The center of the tube is major_radius away from the axis
+/// & the tube itself is minor_radius thick. Put it anywhere else with a `Transform`
+///
+/// u goes around the axis & v around the tube, Starting from the outer rim
+#[derive(Clone)]
+pub struct Torus<T: Material + Clone + Sized> {
+    major_radius: f64,
+    minor_radius: f64,
+    phi_max: f64,
+    material: T,
+}
+
+impl<T: Material + Clone + Sized> Torus<T> {
+    pub fn new(major_radius: f64, minor_radius: f64, material: T) -> Self {
+        Self {
+            major_radius,
+            minor_radius,
+            phi_max: sweep_radians(360.0),
+            material,
+        }
+    }
+
+    /// Only keeps the part from +X up to degrees around the axis, Towards +Z
+    pub fn with_sweep(mut self, degrees: f64) -> Self {
+        self.phi_max = sweep_radians(degrees);
+        self
+    }
+}
+
+impl<T: Material + Clone + Sized> Hitable for Torus<T> {
+    fn hit(&self, ray: &Ray, t_min: f64, t_max: f64) -> Option<HitRecord> {
+        // Quartic is much better behaved with a unit direction, t is scaled back at the end
+        let length = ray.direction.length();
+        let (o, d) = (ray.origin, ray.direction / length);
+
+        // (|p|^2 + R^2 - r^2)^2 = 4 * R^2 * (x^2 + z^2) with p = o + t * d
+        let r2 = self.major_radius * self.major_radius;
+        let e = o.dot(&o) - r2 - self.minor_radius * self.minor_radius;
+        let f = o.dot(&d);
+        let four_r2 = 4.0 * r2;
+
+        let roots = solve_quartic(
+            1.0,
+            4.0 * f,
+            2.0 * e + 4.0 * f * f + four_r2 * d.y() * d.y(),
+            4.0 * f * e + 2.0 * four_r2 * o.y() * d.y(),
+            e * e - four_r2 * (self.minor_radius * self.minor_radius - o.y() * o.y()),
+        );
+
+        let t = nearest(
+            roots.into_iter().map(|t| t / length).collect(),
+            t_min,
+            t_max,
+            |t| azimuth(ray.point_at_parameter(t)) <= self.phi_max,
+        )?;
+
+        let p = ray.point_at_parameter(t);
+        let phi = azimuth(p);
+
+        // Center of the tube closest to p
+        let radial = Vec3::new(p.x(), 0.0, p.z()).unit_vector();
+        let normal = (p - radial * self.major_radius).unit_vector();
+        let theta = normal.y().atan2(normal.dot(&radial)).rem_euclid(2.0 * PI);
+
+        let uv = (phi / self.phi_max, theta / (2.0 * PI));
+
+        let dpdv = (Vec3::new(0.0, theta.cos(), 0.0) - radial * theta.sin())
+            * (2.0 * PI * self.minor_radius);
+
+        let mut hit_rec = HitRecord::new(t, p, normal, &self.material, uv)
+            .with_tangents(around_axis(p, self.phi_max), dpdv);
+        hit_rec.set_face_normal(ray);
+
+        Some(hit_rec)
+    }
+
+    fn bounding_box(&self, _t0: f64, _t1: f64) -> Option<Aabb> {
+        let extent = self.major_radius + self.minor_radius;
+
+        Some(Aabb::new(
+            Vec3::new(-extent, -self.minor_radius, -extent),
+            Vec3::new(extent, self.minor_radius, extent),
+        ))
+    }
+}
@@ -0,0 +1,143 @@
+use rand::{prelude::SmallRng, Rng};
+
+use crate::{
+    hitable::{
+        shapes::{planar_box, Sampleable},
+        HitRecord, Hitable,
+    },
+    types::{Ray, Vec3},
+    Aabb, Material,
+};
+
+/// Flat triangle, Front face is the side from which a, b & c go counter clockwise
+#[derive(Clone)]
+pub struct Triangle<T: Material + Clone + Sized> {
+    a: Vec3,
+    ab: Vec3,
+    ac: Vec3,
+    normal: Vec3,
+    // Texture coordinates at a, b & c
+    uvs: [(f64, f64); 3],
+    material: T,
+}
+
+impl<T: Material + Clone + Sized> Triangle<T> {
+    /// Texture coordinates default to (0, 0), (1, 0) & (0, 1) at a, b & c
+    pub fn new(a: Vec3, b: Vec3, c: Vec3, material: T) -> Self {
+        let (ab, ac) = (b - a, c - a);
+
+        Self {
+            a,
+            ab,
+            ac,
+            normal: ab.cross(&ac).unit_vector(),
+            uvs: [(0.0, 0.0), (1.0, 0.0), (0.0, 1.0)],
+            material,
+        }
+    }
+
+    pub fn with_uvs(mut self, uv_a: (f64, f64), uv_b: (f64, f64), uv_c: (f64, f64)) -> Self {
+        self.uvs = [uv_a, uv_b, uv_c];
+        self
+    }
+}
+
+impl<T: Material + Clone + Sized> Hitable for Triangle<T> {
+    fn hit(&self, ray: &Ray, t_min: f64, t_max: f64) -> Option<HitRecord> {
+        let (t, b1, b2) = intersect_triangle(ray, self.a, self.ab, self.ac)?;
+        if t < t_min || t > t_max {
+            return None;
+        }
+
+        let b0 = 1.0 - b1 - b2;
+        let [(u0, v0), (u1, v1), (u2, v2)] = self.uvs;
+        let uv = (b0 * u0 + b1 * u1 + b2 * u2, b0 * v0 + b1 * v1 + b2 * v2);
+
+        let (dpdu, dpdv) = uv_tangents(self.uvs, self.ab, self.ac, self.normal);
+
+        let mut hit_rec = HitRecord::new(
+            t,
+            ray.point_at_parameter(t),
+            self.normal,
+            &self.material,
+            uv,
+        )
+        .with_tangents(dpdu, dpdv);
+
+        hit_rec.set_face_normal(ray);
+
+        Some(hit_rec)
+    }
+
+    fn bounding_box(&self, _t0: f64, _t1: f64) -> Option<Aabb> {
+        Some(planar_box(&[self.a, self.a + self.ab, self.a + self.ac]))
+    }
+}
+
+impl<T: Material + Clone + Sized> Sampleable for Triangle<T> {
+    fn area(&self) -> f64 {
+        0.5 * self.ab.cross(&self.ac).length()
+    }
+
+    fn sample(&self, rng: &mut SmallRng) -> (Vec3, Vec3) {
+        // Folds the points that land outside back into the triangle
+        let (mut b1, mut b2) = (rng.gen::<f64>(), rng.gen::<f64>());
+        if b1 + b2 > 1.0 {
+            b1 = 1.0 - b1;
+            b2 = 1.0 - b2;
+        }
+
+        (self.a + self.ab * b1 + self.ac * b2, self.normal)
+    }
+}
+
+/// Möller–Trumbore, See, https://cadxfem.org/inf/Fast%20MinimumStorage%20RayTriangle%20Intersection.pdf
+/// (t, b1, b2) where ray crosses the triangle a, a + ab & a + ac. b1 & b2 are the
+/// barycentric weights of the 2nd & 3rd corner, t is left for the caller to check
+pub(crate) fn intersect_triangle(
+    ray: &Ray,
+    a: Vec3,
+    ab: Vec3,
+    ac: Vec3,
+) -> Option<(f64, f64, f64)> {
+    let p = ray.direction.cross(&ac);
+    let determinant = ab.dot(&p);
+
+    // Ray is parallel to the triangle
+    if determinant.abs() < 1e-12 {
+        return None;
+    }
+
+    let inv_determinant = 1.0 / determinant;
+    let s = ray.origin - a;
+    let b1 = s.dot(&p) * inv_determinant;
+    if !(0.0..=1.0).contains(&b1) {
+        return None;
+    }
+
+    let q = s.cross(&ab);
+    let b2 = ray.direction.dot(&q) * inv_determinant;
+    if b2 < 0.0 || b1 + b2 > 1.0 {
+        return None;
+    }
+
+    Some((ac.dot(&q) * inv_determinant, b1, b2))
+}
+
+/// Solves for dp/du & dp/dv from how the uvs at the 3 corners change along the two edges.
+/// Any tangent frame around normal if they don't change
+pub(crate) fn uv_tangents(uvs: [(f64, f64); 3], ab: Vec3, ac: Vec3, normal: Vec3) -> (Vec3, Vec3) {
+    let [(u0, v0), (u1, v1), (u2, v2)] = uvs;
+    let (du1, dv1) = (u1 - u0, v1 - v0);
+    let (du2, dv2) = (u2 - u0, v2 - v0);
+
+    let determinant = du1 * dv2 - dv1 * du2;
+    if determinant.abs() < 1e-12 {
+        return HitRecord::tangent_frame(normal);
+    }
+
+    (
+        (ab * dv2 - ac * dv1) / determinant,
+        (ac * du1 - ab * du2) / determinant,
+    )
+}
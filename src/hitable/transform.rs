@@ -0,0 +1,154 @@
+use std::sync::Arc;
+
+use crate::{
+    hitable::{HitRecord, Hitable},
+    types::{Mat4, Ray, Vec3},
+    Aabb,
+};
+
+/// Instance of a shared object placed into the world with an arbitrary affine transform
+///
+/// Scales, Rotations & shears are combined into a single matrix, So the ray is only moved
+/// into the object's space once no matter how many of them are chained. The object itself
+/// is behind an `Arc`, Every instance of the same geometry only costs a couple of matrices
+pub struct Transform<T: ?Sized> {
+    object: Arc<T>,
+    matrix: Mat4,
+    inverse: Mat4,
+    // Transpose of the inverse, Keeps the normals perpendicular to sheared/scaled surfaces
+    normal_matrix: Mat4,
+}
+
+impl<T: Hitable + ?Sized> Transform<T> {
+    pub fn new(object: Arc<T>) -> Self {
+        Self {
+            object,
+            matrix: Mat4::identity(),
+            inverse: Mat4::identity(),
+            normal_matrix: Mat4::identity(),
+        }
+    }
+
+    /// Applies matrix after all the transforms added so far
+    ///
+    /// Panics if the matrix can't be inverted, e.g. a scale of 0.0 along some axis
+    pub fn with_matrix(mut self, matrix: Mat4) -> Self {
+        self.matrix = matrix * self.matrix;
+        self.inverse = self
+            .matrix
+            .inverse()
+            .expect("transform has to be invertible");
+        self.normal_matrix = self.inverse.transpose();
+        self
+    }
+
+    pub fn with_translation(self, offset: impl Into<Vec3>) -> Self {
+        self.with_matrix(Mat4::translation(offset.into()))
+    }
+
+    /// Rotates by angle(in degrees) about an axis through the origin
+    pub fn with_rotation(self, axis: impl Into<Vec3>, angle: f64) -> Self {
+        self.with_matrix(Mat4::rotation(axis.into(), angle))
+    }
+
+    /// Scale factor along each axis, They don't have to be the same
+    pub fn with_scale(self, factor: impl Into<Vec3>) -> Self {
+        self.with_matrix(Mat4::scale(factor.into()))
+    }
+
+    /// See, `Mat4::shear`
+    pub fn with_shear(self, xy: f64, xz: f64, yx: f64, yz: f64, zx: f64, zy: f64) -> Self {
+        self.with_matrix(Mat4::shear(xy, xz, yx, yz, zx, zy))
+    }
+
+    // Same as the `Hitable` helpers but they add to this transform, So a chain of
+    // them on any object stays a single matrix
+
+    pub fn translate(self, offset: impl Into<Vec3>) -> Self {
+        self.with_translation(offset)
+    }
+
+    pub fn rotate_x(self, angle: f64) -> Self {
+        self.with_rotation(Vec3::new(1.0, 0.0, 0.0), angle)
+    }
+
+    pub fn rotate_y(self, angle: f64) -> Self {
+        self.with_rotation(Vec3::new(0.0, 1.0, 0.0), angle)
+    }
+
+    pub fn rotate_z(self, angle: f64) -> Self {
+        self.with_rotation(Vec3::new(0.0, 0.0, 1.0), angle)
+    }
+}
+
+impl<T: Hitable + ?Sized> Hitable for Transform<T> {
+    fn hit(&self, ray: &Ray, t_min: f64, t_max: f64) -> Option<HitRecord> {
+        let hit = self
+            .object
+            .hit(&to_local(ray, &self.inverse), t_min, t_max)?;
+
+        Some(to_world(hit, &self.matrix, &self.normal_matrix))
+    }
+
+    fn bounding_box(&self, t0: f64, t1: f64) -> Option<Aabb> {
+        let bbox = self.object.bounding_box(t0, t1)?;
+
+        Some(transform_box(&bbox, &self.matrix))
+    }
+
+    fn hit_all(&self, ray: &Ray, t_min: f64, t_max: f64) -> Vec<HitRecord> {
+        self.object
+            .hit_all(&to_local(ray, &self.inverse), t_min, t_max)
+            .into_iter()
+            .map(|hit| to_world(hit, &self.matrix, &self.normal_matrix))
+            .collect()
+    }
+}
+
+/// Moves ray into the object's space, inverse is the inverse of the object's transform
+///
+/// Direction isn't normalized after the transform, So t means the same in both spaces
+pub(super) fn to_local(ray: &Ray, inverse: &Mat4) -> Ray {
+    Ray::new(
+        inverse.transform_point(ray.origin),
+        inverse.transform_vector(ray.direction),
+        ray.time(),
+    )
+}
+
+/// Moves a hit from the object's space back into the world
+///
+/// Object has already turned the normal to face against the local ray, The normal
+/// matrix keeps the sign of the dot product with the direction so front_face still holds
+pub(super) fn to_world<'a>(
+    mut hit: HitRecord<'a>,
+    matrix: &Mat4,
+    normal_matrix: &Mat4,
+) -> HitRecord<'a> {
+    hit.p = matrix.transform_point(hit.p);
+    hit.normal = normal_matrix.transform_vector(hit.normal).unit_vector();
+    hit.dpdu = matrix.transform_vector(hit.dpdu);
+    hit.dpdv = matrix.transform_vector(hit.dpdv);
+
+    hit
+}
+
+/// Box around the 8 transformed corners of bbox
+pub(super) fn transform_box(bbox: &Aabb, matrix: &Mat4) -> Aabb {
+    let mut min = Vec3::splat(f64::MAX);
+    let mut max = Vec3::splat(f64::MIN);
+
+    for corner in 0..8 {
+        let pick = |bit: usize, min: f64, max: f64| if corner & bit == 0 { min } else { max };
+        let p = matrix.transform_point(Vec3::new(
+            pick(1, bbox.min.x(), bbox.max.x()),
+            pick(2, bbox.min.y(), bbox.max.y()),
+            pick(4, bbox.min.z(), bbox.max.z()),
+        ));
+
+        min = Vec3::min(min, p);
+        max = Vec3::max(max, p);
+    }
+
+    Aabb::new(min, max)
+}
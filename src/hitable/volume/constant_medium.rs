@@ -1,11 +1,11 @@
 use crate::{
-    hitable::{HitRecord, Hitable},
+    hitable::{volume::sample_distance, HitRecord, Hitable},
     types::{Ray, Vec3},
     Aabb, Material,
 };
 
 pub struct ConstantMedium<A: Hitable, B: Material> {
-    neg_inv_density: f64,
+    density: f64,
     boundary: A,
     phase_function: B,
 }
@@ -15,38 +15,43 @@ impl<A: Hitable, B: Material> ConstantMedium<A, B> {
         Self {
             boundary,
             phase_function,
-            neg_inv_density: -1.0 / d,
+            density: d,
         }
     }
 }
 
 impl<A: Hitable, B: Material> Hitable for ConstantMedium<A, B> {
     fn hit(&self, ray: &Ray, t_min: f64, t_max: f64) -> Option<HitRecord> {
-        let mut hit1 = self.boundary.hit(ray, f64::MIN, f64::MAX)?;
-        let mut hit2 = self.boundary.hit(ray, hit1.t + 0.0001, f64::MAX)?;
+        // Whole line, So that rays starting inside still know where the medium began
+        let intervals = self.boundary.intervals(ray, f64::MIN, f64::MAX);
 
-        hit1.t = hit1.t.max(t_min);
-        hit2.t = hit2.t.min(t_max);
-
-        if hit1.t >= hit2.t {
-            return None;
-        };
+        let ray_length = ray.direction.length();
+        let mut hit_distance = sample_distance(&mut rand::thread_rng(), self.density);
 
-        hit1.t = hit1.t.max(0.0);
+        // Boundary might be in several pieces, The ray is only inside the medium part of the way
+        let mut t = None;
+        for (enter, exit) in intervals {
+            let (enter, exit) = (enter.max(t_min).max(0.0), exit.min(t_max));
+            if enter >= exit {
+                continue;
+            }
 
-        let ray_length = ray.direction.length();
-        let distance_inside_boundary = (hit2.t - hit1.t) * ray_length;
-        let hit_distance = self.neg_inv_density * rand::random::<f64>().ln();
+            let distance_inside_boundary = (exit - enter) * ray_length;
+            if hit_distance <= distance_inside_boundary {
+                t = Some(enter + hit_distance / ray_length);
+                break;
+            }
 
-        if hit_distance > distance_inside_boundary {
-            return None;
+            hit_distance -= distance_inside_boundary;
         }
 
-        let t = hit1.t + hit_distance / ray_length;
+        let t = t?;
+        let p = ray.point_at_parameter(t);
 
         Some(HitRecord {
             t,
-            p: ray.point_at_parameter(t),
+            p,
+            local_p: p,
             material: &self.phase_function,
             u: 0.0,
             v: 0.0,
@@ -54,6 +59,12 @@ impl<A: Hitable, B: Material> Hitable for ConstantMedium<A, B> {
             // Arbitrary
             front_face: true,
             normal: Vec3::new(1.0, 0.0, 0.0),
+            dpdu: Vec3::new(0.0, 1.0, 0.0),
+            dpdv: Vec3::new(0.0, 0.0, 1.0),
+            local_dpdu: Vec3::new(0.0, 1.0, 0.0),
+            local_dpdv: Vec3::new(0.0, 0.0, 1.0),
+            footprint: 0.0,
+            time: 0.0,
         })
     }
 
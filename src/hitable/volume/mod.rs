@@ -1,3 +1,11 @@
 mod constant_medium;
 
 pub use constant_medium::ConstantMedium;
+
+use rand::Rng;
+
+/// Samples the distance a ray travels through a homogeneous medium of the given density
+/// before it hits a particle. Distances are exponentially distributed with mean 1 / density
+pub fn sample_distance<R: Rng + ?Sized>(rng: &mut R, density: f64) -> f64 {
+    -(1.0 - rng.gen::<f64>()).ln() / density
+}
@@ -1,6 +1,7 @@
 #![allow(clippy::suspicious_arithmetic_impl)]
 
 mod aabb;
+mod background;
 mod camera;
 mod demos;
 mod hitable;
@@ -9,6 +10,7 @@ mod texture;
 mod types;
 
 pub use aabb::Aabb;
+pub use background::Background;
 pub use camera::Camera;
 pub use materials::Material;
 pub use texture::Texture;
@@ -116,6 +118,81 @@ fn run(mut width: usize, mut height: usize) -> Result<(), String> {
                             active_demo = DemoWrapper::HitableList(Box::new(demos::CornellBox {}));
                             should_update = true;
                         }
+                        Some(Keycode::Num9) => {
+                            active_demo = DemoWrapper::BVHNode(Box::new(demos::BumpMapping {}));
+                            should_update = true;
+                        }
+                        Some(Keycode::Num0) => {
+                            active_demo = DemoWrapper::BVHNode(Box::new(demos::AlphaCutout {}));
+                            should_update = true;
+                        }
+                        Some(Keycode::Q) => {
+                            active_demo =
+                                DemoWrapper::BVHNode(Box::new(demos::SubsurfaceScattering {}));
+                            should_update = true;
+                        }
+                        Some(Keycode::W) => {
+                            active_demo =
+                                DemoWrapper::BVHNode(Box::new(demos::ThinFilmInterference {}));
+                            should_update = true;
+                        }
+                        Some(Keycode::E) => {
+                            active_demo =
+                                DemoWrapper::BVHNode(Box::new(demos::EnvironmentLighting {}));
+                            should_update = true;
+                        }
+                        Some(Keycode::R) => {
+                            active_demo =
+                                DemoWrapper::BVHNode(Box::new(demos::ProceduralTextures {}));
+                            should_update = true;
+                        }
+                        Some(Keycode::T) => {
+                            active_demo = DemoWrapper::BVHNode(Box::new(demos::TextureNodes {}));
+                            should_update = true;
+                        }
+                        Some(Keycode::Y) => {
+                            active_demo = DemoWrapper::BVHNode(Box::new(demos::CheckerSpaces {}));
+                            should_update = true;
+                        }
+                        Some(Keycode::U) => {
+                            active_demo =
+                                DemoWrapper::BVHNode(Box::new(demos::TransformInstances {}));
+                            should_update = true;
+                        }
+                        Some(Keycode::I) => {
+                            active_demo =
+                                DemoWrapper::BVHNode(Box::new(demos::MotionTransforms {}));
+                            should_update = true;
+                        }
+                        Some(Keycode::O) => {
+                            active_demo = DemoWrapper::BVHNode(Box::new(demos::PlanarShapes {}));
+                            should_update = true;
+                        }
+                        Some(Keycode::P) => {
+                            active_demo = DemoWrapper::BVHNode(Box::new(demos::CurvedShapes {}));
+                            should_update = true;
+                        }
+                        Some(Keycode::A) => {
+                            active_demo = DemoWrapper::BVHNode(Box::new(demos::CsgShapes {}));
+                            should_update = true;
+                        }
+                        Some(Keycode::D) => {
+                            active_demo = DemoWrapper::BVHNode(Box::new(demos::SdfShapes {}));
+                            should_update = true;
+                        }
+                        Some(Keycode::F) => {
+                            active_demo = DemoWrapper::BVHNode(Box::new(demos::Terrain {}));
+                            should_update = true;
+                        }
+                        Some(Keycode::G) => {
+                            active_demo = DemoWrapper::BVHNode(Box::new(demos::HairAndGrass {}));
+                            should_update = true;
+                        }
+                        Some(Keycode::H) => {
+                            active_demo =
+                                DemoWrapper::BVHNode(Box::new(demos::SubdivisionSurfaces {}));
+                            should_update = true;
+                        }
                         None => unreachable!(),
                         _ => (),
                     };
@@ -153,7 +230,7 @@ fn run(mut width: usize, mut height: usize) -> Result<(), String> {
 
 #[cfg(not(feature = "gui"))]
 fn run(width: usize, height: usize) -> Result<(), String> {
-    let demos: [DemoWrapper; 8] = [
+    let demos: [DemoWrapper; 25] = [
         DemoWrapper::BVHNode(Box::new(demos::CheckeredMotionBlur {})),
         DemoWrapper::BVHNode(Box::new(demos::TwoSpheres {})),
         DemoWrapper::BVHNode(Box::new(demos::PerlinNoiseBall {})),
@@ -162,6 +239,23 @@ fn run(width: usize, height: usize) -> Result<(), String> {
         DemoWrapper::BVHNode(Box::new(demos::Instances {})),
         DemoWrapper::BVHNode(Box::new(demos::CornellSmokeAndFog {})),
         DemoWrapper::HitableList(Box::new(demos::CornellBox {})),
+        DemoWrapper::BVHNode(Box::new(demos::BumpMapping {})),
+        DemoWrapper::BVHNode(Box::new(demos::AlphaCutout {})),
+        DemoWrapper::BVHNode(Box::new(demos::SubsurfaceScattering {})),
+        DemoWrapper::BVHNode(Box::new(demos::ThinFilmInterference {})),
+        DemoWrapper::BVHNode(Box::new(demos::EnvironmentLighting {})),
+        DemoWrapper::BVHNode(Box::new(demos::ProceduralTextures {})),
+        DemoWrapper::BVHNode(Box::new(demos::TextureNodes {})),
+        DemoWrapper::BVHNode(Box::new(demos::CheckerSpaces {})),
+        DemoWrapper::BVHNode(Box::new(demos::TransformInstances {})),
+        DemoWrapper::BVHNode(Box::new(demos::MotionTransforms {})),
+        DemoWrapper::BVHNode(Box::new(demos::PlanarShapes {})),
+        DemoWrapper::BVHNode(Box::new(demos::CurvedShapes {})),
+        DemoWrapper::BVHNode(Box::new(demos::CsgShapes {})),
+        DemoWrapper::BVHNode(Box::new(demos::SdfShapes {})),
+        DemoWrapper::BVHNode(Box::new(demos::Terrain {})),
+        DemoWrapper::BVHNode(Box::new(demos::HairAndGrass {})),
+        DemoWrapper::BVHNode(Box::new(demos::SubdivisionSurfaces {})),
     ];
 
     for demo in demos.iter() {
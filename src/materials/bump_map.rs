@@ -0,0 +1,132 @@
+use rand::prelude::SmallRng;
+
+use crate::{
+    hitable::HitRecord,
+    types::{Ray, Vec3},
+    Material, Texture,
+};
+
+// Step size in texture space used to take finite differences of the height field
+const DELTA: f64 = 0.0005;
+
+/// Perturbs the shading normal of the wrapped material as if the surface was displaced
+/// along the normal by the value of a scalar texture. Only the average of the texture
+/// channels is used, So grayscale textures like `PerlinNoise` work as is.
+#[derive(Clone)]
+pub struct BumpMap<M, T> {
+    material: M,
+    height: T,
+    scale: f64,
+}
+
+impl<M: Material, T: Texture> BumpMap<M, T> {
+    /// scale is the displacement in world units for a texture value of 1.0
+    pub fn new(material: M, height: T, scale: f64) -> Self {
+        Self {
+            material,
+            height,
+            scale,
+        }
+    }
+
+    /// Goes through `Texture::value_at`, So object space textures stick to instanced objects
+    fn height_at(&self, hit_rec: &HitRecord) -> f64 {
+        let value = self.height.value_at(hit_rec);
+
+        self.scale * (value.x() + value.y() + value.z()) / 3.0
+    }
+}
+
+impl<M: Material, T: Texture + Send + Sync> BumpMap<M, T> {
+    fn shading_record<'a>(&self, hit_rec: &HitRecord<'a>) -> HitRecord<'a> {
+        let HitRecord {
+            normal, dpdu, dpdv, ..
+        } = *hit_rec;
+
+        // Same hit moved by du & dv along the surface, In world & in object space
+        let moved = |du: f64, dv: f64| {
+            let mut moved = hit_rec.clone();
+            moved.u += du;
+            moved.v += dv;
+            moved.p += dpdu * du + dpdv * dv;
+            moved.local_p += hit_rec.local_dpdu * du + hit_rec.local_dpdv * dv;
+            moved
+        };
+
+        let height = self.height_at(hit_rec);
+        let height_du = self.height_at(&moved(DELTA, 0.0));
+        let height_dv = self.height_at(&moved(0.0, DELTA));
+
+        // Derivatives of the displaced surface p + h(u, v) * n
+        // The change in normal is small enough to be ignored here
+        let displaced_dpdu = dpdu + normal * ((height_du - height) / DELTA);
+        let displaced_dpdv = dpdv + normal * ((height_dv - height) / DELTA);
+
+        let bumped_normal = displaced_dpdu.cross(&displaced_dpdv).unit_vector();
+
+        let mut shading_rec = hit_rec.clone();
+        shading_rec.normal = if bumped_normal.dot(&normal) < 0.0 {
+            -bumped_normal
+        } else {
+            bumped_normal
+        };
+
+        shading_rec
+    }
+}
+
+impl<M: Material, T: Texture + Send + Sync> Material for BumpMap<M, T> {
+    fn scatter(&self, ray: &Ray, hit_rec: &HitRecord, rng: &mut SmallRng) -> (Vec3, Option<Ray>) {
+        self.material
+            .scatter(ray, &self.shading_record(hit_rec), rng)
+    }
+
+    fn scattering_pdf(&self, ray: &Ray, hit_rec: &HitRecord, scattered: &Ray) -> Option<f64> {
+        self.material
+            .scattering_pdf(ray, &self.shading_record(hit_rec), scattered)
+    }
+
+    fn emit(&self, ray: &Ray, hit_rec: &HitRecord) -> Vec3 {
+        self.material.emit(ray, hit_rec)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::BumpMap;
+    use crate::{
+        hitable::{shapes::Sphere, Hitable},
+        materials::Lambertian,
+        texture::{Perlin, PerlinNoise, Solid},
+        types::{Ray, Vec3},
+    };
+
+    #[test]
+    fn object_space_bumps_move_with_the_object() {
+        let noise = PerlinNoise::from_perlin(Arc::new(Perlin::with_seed(3)), 4.0).in_object_space();
+        let bump = BumpMap::new(Lambertian::new(Solid::new(Vec3::splat(0.5))), noise, 0.1);
+
+        let offset = Vec3::new(4.0, -2.0, 7.5);
+        let sphere = Sphere::new(Vec3::splat(0.0), 1.0, bump.clone());
+        let moved = Sphere::new(Vec3::splat(0.0), 1.0, bump.clone()).translate(offset);
+
+        let origin = Vec3::new(-5.0, 0.3, 0.2);
+        let direction = Vec3::new(5.0, -0.1, 0.15);
+
+        let hit = sphere
+            .hit(&Ray::new(origin, direction, 0.0), 0.001, f64::MAX)
+            .unwrap();
+        let moved_hit = moved
+            .hit(&Ray::new(origin + offset, direction, 0.0), 0.001, f64::MAX)
+            .unwrap();
+
+        let normal = bump.shading_record(&hit).normal;
+        let moved_normal = bump.shading_record(&moved_hit).normal;
+
+        // Bumps actually tilt the normal, Otherwise the test would pass for any texture
+        assert!((normal - hit.normal).length() > 1e-3);
+        assert!((normal - moved_normal).length() < 1e-9);
+    }
+}
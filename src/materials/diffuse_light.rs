@@ -1,18 +1,88 @@
-use crate::{types::Vec3, Material, Texture};
+use crate::{
+    hitable::HitRecord,
+    types::{Ray, Vec3},
+    Material, Texture,
+};
+
+#[derive(Clone)]
+enum Emission {
+    TwoSided,
+    // Only emits from the front face of the surface
+    OneSided,
+    // One sided with the emission fading out away from the normal.
+    // Same as the spot light in pbrt, Full intensity up to falloff start
+    // and then a smooth falloff to zero at the edge of the cone
+    Spotlight {
+        cos_total_width: f64,
+        cos_falloff_start: f64,
+    },
+}
 
 #[derive(Clone)]
 pub struct DiffuseLight<T: Texture> {
     emit: T,
+    emission: Emission,
 }
 
 impl<T: Texture> DiffuseLight<T> {
     pub fn new(emit: T) -> Self {
-        Self { emit }
+        Self {
+            emit,
+            emission: Emission::TwoSided,
+        }
+    }
+
+    pub fn one_sided(emit: T) -> Self {
+        Self {
+            emit,
+            emission: Emission::OneSided,
+        }
+    }
+
+    /// total_width & falloff_start are the half angles of the cone in degrees
+    #[allow(dead_code)]
+    pub fn spotlight(emit: T, total_width: f64, falloff_start: f64) -> Self {
+        Self {
+            emit,
+            emission: Emission::Spotlight {
+                cos_total_width: total_width.to_radians().cos(),
+                cos_falloff_start: falloff_start.min(total_width).to_radians().cos(),
+            },
+        }
     }
 }
 
 impl<T: Texture + Send + Sync> Material for DiffuseLight<T> {
-    fn emit(&self, u: f64, v: f64, p: Vec3) -> Vec3 {
-        self.emit.value(u, v, p)
+    fn emit(&self, ray: &Ray, hit_rec: &HitRecord) -> Vec3 {
+        let scale = match self.emission {
+            Emission::TwoSided => 1.0,
+            Emission::OneSided | Emission::Spotlight { .. } if !hit_rec.front_face => 0.0,
+            Emission::OneSided => 1.0,
+            Emission::Spotlight {
+                cos_total_width,
+                cos_falloff_start,
+            } => {
+                // normal is facing the ray, So this is the cosine of the angle
+                // between the direction of emission and the normal
+                let cosine = -ray.direction.unit_vector().dot(&hit_rec.normal);
+
+                smoothstep(cos_total_width, cos_falloff_start, cosine)
+            }
+        };
+
+        if scale > 0.0 {
+            self.emit.value_at(hit_rec) * scale
+        } else {
+            Vec3::splat(0.0)
+        }
+    }
+}
+
+fn smoothstep(edge0: f64, edge1: f64, x: f64) -> f64 {
+    if edge0 >= edge1 {
+        return if x >= edge1 { 1.0 } else { 0.0 };
     }
+
+    let t = ((x - edge0) / (edge1 - edge0)).clamp(0.0, 1.0);
+    t * t * (3.0 - 2.0 * t)
 }
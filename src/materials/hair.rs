@@ -0,0 +1,394 @@
+use std::f64::consts::{LN_2, PI};
+
+use rand::{prelude::SmallRng, Rng};
+
+use crate::{
+    hitable::HitRecord,
+    types::{Ray, Vec3},
+    Material,
+};
+
+// Lobes for light reflected off the surface(R), Passing through the fiber(TT),
+// Reflected once inside it(TRT) & everything after that lumped together
+const LOBES: usize = 4;
+
+/// Absorption of the two pigments in hair per unit of concentration,
+/// From "An Energy-Conserving Hair Reflectance Model" by d'Eon et al.
+const EUMELANIN: (f64, f64, f64) = (0.419, 0.697, 1.37);
+const PHEOMELANIN: (f64, f64, f64) = (0.187, 0.4, 1.05);
+
+#[derive(Debug, Copy, Clone)]
+enum Pigment {
+    Melanin {
+        eumelanin: f64,
+        pheomelanin: f64,
+    },
+    /// Color the hair ends up with after all the scattering, The absorption that gives it
+    /// depends on the azimuthal roughness
+    Color(Vec3),
+}
+
+/// Scattering off hair fibers, Marschner's model with the longitudinal lobes of d'Eon et al.
+/// as described in "Physically Based Rendering", Chapter 9.9 (3rd edition)
+///
+/// Expects u to go along the fiber & v across it like on a `Curve`, v is what says where
+/// on the width of the fiber the ray came in
+#[derive(Clone)]
+pub struct Hair {
+    pigment: Pigment,
+    refraction_index: f64,
+    // Variance of each longitudinal lobe
+    variances: [f64; LOBES],
+    // beta_n, Only kept around to turn a color into absorption
+    azimuthal_roughness: f64,
+    // Scale of the logistic distribution of the azimuthal lobes
+    azimuthal_scale: f64,
+    // sin & cos of 2^k times the tilt of the cuticle scales
+    sin_2k_alpha: [f64; 3],
+    cos_2k_alpha: [f64; 3],
+}
+
+impl Hair {
+    /// Natural hair color from the concentration of the dark brown eumelanin & the
+    /// red pheomelanin. ~0.3 eumelanin is blonde, ~1.3 brown & 8.0 black
+    pub fn from_melanin(eumelanin: f64, pheomelanin: f64) -> Self {
+        Self::new(Pigment::Melanin {
+            eumelanin,
+            pheomelanin,
+        })
+    }
+
+    /// Hair that looks roughly like color, e.g. for dyed hair or fur
+    pub fn from_color(color: Vec3) -> Self {
+        Self::new(Pigment::Color(color))
+    }
+
+    fn new(pigment: Pigment) -> Self {
+        Self {
+            pigment,
+            refraction_index: 1.55,
+            variances: [0.0; LOBES],
+            azimuthal_roughness: 0.0,
+            azimuthal_scale: 0.0,
+            sin_2k_alpha: [0.0; 3],
+            cos_2k_alpha: [0.0; 3],
+        }
+        .with_roughness(0.3, 0.3)
+        .with_scale_angle(2.0)
+    }
+
+    /// Both in [0, 1]. longitudinal spreads the highlights along the fiber,
+    /// azimuthal around it & makes it look softer
+    pub fn with_roughness(mut self, longitudinal: f64, azimuthal: f64) -> Self {
+        let beta_m = longitudinal;
+        let v = (0.726 * beta_m + 0.812 * beta_m.powi(2) + 3.7 * beta_m.powi(20)).powi(2);
+        self.variances = [v, 0.25 * v, 4.0 * v, 4.0 * v];
+
+        let beta_n = azimuthal;
+        self.azimuthal_roughness = beta_n;
+        self.azimuthal_scale =
+            (PI / 8.0).sqrt() * (0.265 * beta_n + 1.194 * beta_n.powi(2) + 5.372 * beta_n.powi(22));
+
+        self
+    }
+
+    /// Tilt of the scales on the surface of the fiber in degrees,
+    /// Shifts the highlights along the fiber
+    pub fn with_scale_angle(mut self, degrees: f64) -> Self {
+        let (sin, cos) = degrees.to_radians().sin_cos();
+
+        self.sin_2k_alpha[0] = sin;
+        self.cos_2k_alpha[0] = cos;
+        for i in 1..3 {
+            self.sin_2k_alpha[i] = 2.0 * self.cos_2k_alpha[i - 1] * self.sin_2k_alpha[i - 1];
+            self.cos_2k_alpha[i] =
+                self.cos_2k_alpha[i - 1].powi(2) - self.sin_2k_alpha[i - 1].powi(2);
+        }
+
+        self
+    }
+
+    #[allow(dead_code)]
+    pub fn with_refraction_index(mut self, refraction_index: f64) -> Self {
+        self.refraction_index = refraction_index;
+        self
+    }
+
+    fn absorption(&self) -> Vec3 {
+        match self.pigment {
+            Pigment::Melanin {
+                eumelanin,
+                pheomelanin,
+            } => Vec3::from(EUMELANIN) * eumelanin + Vec3::from(PHEOMELANIN) * pheomelanin,
+            Pigment::Color(color) => {
+                let b = self.azimuthal_roughness;
+                let denominator = 5.969 - 0.215 * b + 2.532 * b.powi(2) - 10.73 * b.powi(3)
+                    + 5.574 * b.powi(4)
+                    + 0.245 * b.powi(5);
+
+                let channel = |c: f64| (c.max(1e-4).ln() / denominator).powi(2);
+                Vec3::new(channel(color.x()), channel(color.y()), channel(color.z()))
+            }
+        }
+    }
+
+    /// Longitudinal angle of each lobe's output direction, Tilted by the scales
+    fn tilted(&self, p: usize, sin_theta_o: f64, cos_theta_o: f64) -> (f64, f64) {
+        let (sin, cos) = match p {
+            0 => (-self.sin_2k_alpha[1], self.cos_2k_alpha[1]),
+            1 => (self.sin_2k_alpha[0], self.cos_2k_alpha[0]),
+            2 => (self.sin_2k_alpha[2], self.cos_2k_alpha[2]),
+            _ => (0.0, 1.0),
+        };
+
+        (
+            sin_theta_o * cos + cos_theta_o * sin,
+            (cos_theta_o * cos - sin_theta_o * sin).abs(),
+        )
+    }
+
+    /// Attenuation of every lobe & the angles inside the fiber for the given h
+    fn attenuation(&self, cos_theta_o: f64, sin_theta_o: f64, h: f64) -> ([Vec3; LOBES], f64) {
+        let eta = self.refraction_index;
+
+        // Refracted direction's longitudinal & azimuthal angles
+        let sin_theta_t = sin_theta_o / eta;
+        let cos_theta_t = (1.0 - sin_theta_t * sin_theta_t).max(0.0).sqrt();
+        let eta_p = (eta * eta - sin_theta_o * sin_theta_o).sqrt() / cos_theta_o;
+        let sin_gamma_t = (h / eta_p).clamp(-1.0, 1.0);
+        let cos_gamma_t = (1.0 - sin_gamma_t * sin_gamma_t).sqrt();
+
+        // Transmittance for one pass through the fiber
+        let path = 2.0 * cos_gamma_t / cos_theta_t;
+        let sigma_a = self.absorption();
+        let transmittance = Vec3::new(
+            (-sigma_a.x() * path).exp(),
+            (-sigma_a.y() * path).exp(),
+            (-sigma_a.z() * path).exp(),
+        );
+
+        let cos_gamma_o = (1.0 - h * h).max(0.0).sqrt();
+        let f = fresnel(cos_theta_o * cos_gamma_o, eta);
+
+        let mut ap = [Vec3::splat(0.0); LOBES];
+        ap[0] = Vec3::splat(f);
+        ap[1] = transmittance * (1.0 - f).powi(2);
+        ap[2] = ap[1] * transmittance * f;
+        ap[3] = ap[2] * transmittance * f / (Vec3::splat(1.0) - transmittance * f);
+
+        (ap, sin_gamma_t.asin())
+    }
+
+    /// Returns the BSDF times the cosine term & the pdf of sampling wi, Both in the
+    /// local frame with X along the fiber
+    fn evaluate(&self, wo: Vec3, wi: Vec3, h: f64) -> (Vec3, f64) {
+        let (sin_theta_o, cos_theta_o) = (wo.x(), (1.0 - wo.x() * wo.x()).max(0.0).sqrt());
+        let (sin_theta_i, cos_theta_i) = (wi.x(), (1.0 - wi.x() * wi.x()).max(0.0).sqrt());
+        let phi = wi.z().atan2(wi.y()) - wo.z().atan2(wo.y());
+
+        let gamma_o = h.asin();
+        let (ap, gamma_t) = self.attenuation(cos_theta_o, sin_theta_o, h);
+        let weights = lobe_weights(&ap);
+
+        let mut value = Vec3::splat(0.0);
+        let mut pdf = 0.0;
+        for p in 0..LOBES {
+            let (sin_theta_op, cos_theta_op) = self.tilted(p, sin_theta_o, cos_theta_o);
+            let mp = longitudinal(
+                cos_theta_i,
+                cos_theta_op,
+                sin_theta_i,
+                sin_theta_op,
+                self.variances[p],
+            );
+            let np = if p < LOBES - 1 {
+                azimuthal(phi, p, self.azimuthal_scale, gamma_o, gamma_t)
+            } else {
+                1.0 / (2.0 * PI)
+            };
+
+            value += ap[p] * (mp * np);
+            pdf += weights[p] * mp * np;
+        }
+
+        (value, pdf)
+    }
+}
+
+impl Material for Hair {
+    fn scatter(
+        &self,
+        ray_in: &Ray,
+        hit_rec: &HitRecord,
+        rng: &mut SmallRng,
+    ) -> (Vec3, Option<Ray>) {
+        // Local frame, X along the fiber & Z the normal
+        let normal = hit_rec.normal;
+        let along = (hit_rec.dpdu - normal * normal.dot(&hit_rec.dpdu)).unit_vector();
+        let across = normal.cross(&along);
+        let to_local = |v: Vec3| Vec3::new(v.dot(&along), v.dot(&across), v.dot(&normal));
+
+        let wo = to_local(-ray_in.direction.unit_vector());
+        let h = (2.0 * hit_rec.v - 1.0).clamp(-1.0, 1.0);
+
+        let (sin_theta_o, cos_theta_o) = (wo.x(), (1.0 - wo.x() * wo.x()).max(0.0).sqrt());
+        let phi_o = wo.z().atan2(wo.y());
+
+        // Pick a lobe by how much light it carries
+        let (ap, gamma_t) = self.attenuation(cos_theta_o, sin_theta_o, h);
+        let weights = lobe_weights(&ap);
+        let mut pick = rng.gen::<f64>();
+        let mut p = 0;
+        while p < LOBES - 1 && pick >= weights[p] {
+            pick -= weights[p];
+            p += 1;
+        }
+
+        // Longitudinal angle around the lobe's tilted mirror direction
+        let (sin_theta_op, cos_theta_op) = self.tilted(p, sin_theta_o, cos_theta_o);
+        let v = self.variances[p];
+        let u1 = rng.gen::<f64>().max(1e-5);
+        let cos_theta = 1.0 + v * (u1 + (1.0 - u1) * (-2.0 / v).exp()).ln();
+        let sin_theta = (1.0 - cos_theta * cos_theta).max(0.0).sqrt();
+        let cos_phi = (2.0 * PI * rng.gen::<f64>()).cos();
+        let sin_theta_i =
+            (-cos_theta * sin_theta_op + sin_theta * cos_phi * cos_theta_op).clamp(-1.0, 1.0);
+        let cos_theta_i = (1.0 - sin_theta_i * sin_theta_i).sqrt();
+
+        // Azimuthal angle
+        let d_phi = if p < LOBES - 1 {
+            phi(p, h.asin(), gamma_t)
+                + sample_trimmed_logistic(rng.gen(), self.azimuthal_scale, -PI, PI)
+        } else {
+            2.0 * PI * rng.gen::<f64>()
+        };
+        let phi_i = phi_o + d_phi;
+
+        let wi = Vec3::new(
+            sin_theta_i,
+            cos_theta_i * phi_i.cos(),
+            cos_theta_i * phi_i.sin(),
+        );
+
+        let (value, pdf) = self.evaluate(wo, wi, h);
+        if pdf <= 0.0 {
+            return (Vec3::splat(0.0), None);
+        }
+
+        let direction = along * wi.x() + across * wi.y() + normal * wi.z();
+
+        (
+            value / pdf,
+            Some(Ray::new(hit_rec.p, direction, ray_in.time())),
+        )
+    }
+}
+
+/// Chance of picking each lobe, By the average of their attenuation
+fn lobe_weights(ap: &[Vec3; LOBES]) -> [f64; LOBES] {
+    let sums = ap.map(|a| (a.x() + a.y() + a.z()) / 3.0);
+    let total: f64 = sums.iter().sum();
+
+    if total <= 0.0 {
+        return [1.0 / LOBES as f64; LOBES];
+    }
+
+    sums.map(|s| s / total)
+}
+
+/// Fresnel reflectance of a dielectric for unpolarized light coming from air
+fn fresnel(cos_theta_i: f64, eta: f64) -> f64 {
+    let cos_theta_i = cos_theta_i.clamp(-1.0, 1.0);
+    let sin_theta_t = (1.0 - cos_theta_i * cos_theta_i).max(0.0).sqrt() / eta;
+
+    if sin_theta_t >= 1.0 {
+        return 1.0;
+    }
+
+    let cos_theta_t = (1.0 - sin_theta_t * sin_theta_t).sqrt();
+    let parallel = (eta * cos_theta_i - cos_theta_t) / (eta * cos_theta_i + cos_theta_t);
+    let perpendicular = (cos_theta_i - eta * cos_theta_t) / (cos_theta_i + eta * cos_theta_t);
+
+    (parallel * parallel + perpendicular * perpendicular) / 2.0
+}
+
+/// d'Eon's longitudinal scattering function for a lobe with variance v
+fn longitudinal(
+    cos_theta_i: f64,
+    cos_theta_o: f64,
+    sin_theta_i: f64,
+    sin_theta_o: f64,
+    v: f64,
+) -> f64 {
+    let a = cos_theta_i * cos_theta_o / v;
+    let b = sin_theta_i * sin_theta_o / v;
+
+    // Low variances overflow the Bessel function, So it's done in log space
+    if v <= 0.1 {
+        (log_bessel_i0(a) - b - 1.0 / v + LN_2 + (1.0 / (2.0 * v)).ln()).exp()
+    } else {
+        (-b).exp() * bessel_i0(a) / ((1.0 / v).sinh() * 2.0 * v)
+    }
+}
+
+/// Modified Bessel function of the first kind
+fn bessel_i0(x: f64) -> f64 {
+    let mut value = 0.0;
+    let mut x2i = 1.0;
+    let mut factorial = 1.0;
+    let mut four_i = 1.0;
+
+    for i in 0..10 {
+        if i > 1 {
+            factorial *= i as f64;
+        }
+        value += x2i / (four_i * factorial * factorial);
+        four_i *= 4.0;
+        x2i *= x * x;
+    }
+
+    value
+}
+
+fn log_bessel_i0(x: f64) -> f64 {
+    if x > 12.0 {
+        x + 0.5 * (-(2.0 * PI).ln() + (1.0 / x).ln() + 1.0 / (8.0 * x))
+    } else {
+        bessel_i0(x).ln()
+    }
+}
+
+/// Azimuthal angle light leaves at after p bounces inside the fiber
+fn phi(p: usize, gamma_o: f64, gamma_t: f64) -> f64 {
+    let p = p as f64;
+
+    2.0 * p * gamma_t - 2.0 * gamma_o + p * PI
+}
+
+fn azimuthal(phi_diff: f64, p: usize, s: f64, gamma_o: f64, gamma_t: f64) -> f64 {
+    let d_phi = (phi_diff - phi(p, gamma_o, gamma_t) + PI).rem_euclid(2.0 * PI) - PI;
+
+    trimmed_logistic(d_phi, s, -PI, PI)
+}
+
+fn logistic(x: f64, s: f64) -> f64 {
+    let x = x.abs();
+
+    (-x / s).exp() / (s * (1.0 + (-x / s).exp()).powi(2))
+}
+
+fn logistic_cdf(x: f64, s: f64) -> f64 {
+    1.0 / (1.0 + (-x / s).exp())
+}
+
+/// Logistic distribution limited to [a, b] & scaled back up to integrate to 1
+fn trimmed_logistic(x: f64, s: f64, a: f64, b: f64) -> f64 {
+    logistic(x, s) / (logistic_cdf(b, s) - logistic_cdf(a, s))
+}
+
+fn sample_trimmed_logistic(u: f64, s: f64, a: f64, b: f64) -> f64 {
+    let k = logistic_cdf(b, s) - logistic_cdf(a, s);
+    let x = -s * (1.0 / (u * k + logistic_cdf(a, s)) - 1.0).ln();
+
+    x.clamp(a, b)
+}
@@ -20,7 +20,7 @@ impl<T: Texture> Isotropic<T> {
 impl<T: Texture + Send + Sync> Material for Isotropic<T> {
     fn scatter(&self, ray: &Ray, hit_rec: &HitRecord, rng: &mut SmallRng) -> (Vec3, Option<Ray>) {
         (
-            self.texture.value(hit_rec.u, hit_rec.v, hit_rec.p),
+            self.texture.value_at(hit_rec),
             Some(Ray::new(
                 hit_rec.p,
                 random_point_in_unit_sphere(rng),
@@ -2,7 +2,7 @@ use rand::prelude::SmallRng;
 
 use crate::{
     hitable::HitRecord,
-    materials::random_point_in_unit_sphere,
+    materials::random_unit_vector,
     types::{Ray, Vec3},
     Material, Texture,
 };
@@ -20,12 +20,19 @@ impl<T: Texture> Lambertian<T> {
 
 impl<T: Texture + Send + Sync> Material for Lambertian<T> {
     fn scatter(&self, ray: &Ray, hit_rec: &HitRecord, rng: &mut SmallRng) -> (Vec3, Option<Ray>) {
-        let scatter_direction = hit_rec.normal + random_point_in_unit_sphere(rng);
+        // Cosine weighted direction around the normal
+        let mut scatter_direction = hit_rec.normal + random_unit_vector(rng);
+        if scatter_direction.sq_len() < 1e-16 {
+            scatter_direction = hit_rec.normal;
+        }
         let scattered_ray = Ray::new(hit_rec.p, scatter_direction, ray.time());
 
-        (
-            self.albedo.value(hit_rec.u, hit_rec.v, hit_rec.p),
-            Some(scattered_ray),
-        )
+        (self.albedo.value_at(hit_rec), Some(scattered_ray))
+    }
+
+    fn scattering_pdf(&self, _ray: &Ray, hit_rec: &HitRecord, scattered: &Ray) -> Option<f64> {
+        let cosine = hit_rec.normal.dot(&scattered.direction.unit_vector());
+
+        Some(cosine.max(0.0) / std::f64::consts::PI)
     }
 }
@@ -1,15 +1,25 @@
+mod bump_map;
 mod dielectric;
 mod diffuse_light;
+mod hair;
 mod isotropic;
 mod lambertian;
 mod metal;
+mod normal_map;
+mod subsurface;
+mod thin_film;
 
+pub use bump_map::BumpMap;
 pub use dielectric::Dielectric;
 pub use diffuse_light::DiffuseLight;
+pub use hair::Hair;
 pub use isotropic::Isotropic;
 pub use lambertian::Lambertian;
 pub use metal::Metal;
+pub use normal_map::NormalMap;
 use rand::{prelude::SmallRng, Rng};
+pub use subsurface::Subsurface;
+pub use thin_film::{Substrate, ThinFilm};
 
 use crate::{
     hitable::HitRecord,
@@ -28,7 +38,16 @@ pub trait Material: Send + Sync {
         (Vec3::splat(0.0), None)
     }
 
-    fn emit(&self, _u: f64, _v: f64, _p: Vec3) -> Vec3 {
+    // scattering_pdf is the probability density of scatter picking the direction of scattered
+    // It's only implemented by materials that scatter into all directions and return
+    // the albedo as the attenuation. Renderer can then send the scattered ray towards the
+    // lights instead and weight the result by this pdf. None for everything else
+    fn scattering_pdf(&self, _ray: &Ray, _hit_rec: &HitRecord, _scattered: &Ray) -> Option<f64> {
+        None
+    }
+
+    // emit returns the light emitted towards the origin of the incoming ray
+    fn emit(&self, _ray: &Ray, _hit_rec: &HitRecord) -> Vec3 {
         Vec3::splat(0.0)
     }
 }
@@ -73,6 +92,14 @@ fn random_point_in_unit_sphere<R: Rng + ?Sized>(rng: &mut R) -> Vec3 {
     Vec3::new(x, y, z)
 }
 
+fn random_unit_vector<R: Rng + ?Sized>(rng: &mut R) -> Vec3 {
+    let z: f64 = rng.gen_range(-1.0..=1.0);
+    let theta = rng.gen::<f64>() * 2.0 * std::f64::consts::PI;
+    let r = (1.0 - z * z).sqrt();
+
+    Vec3::new(r * theta.cos(), r * theta.sin(), z)
+}
+
 pub trait MaterialBuilder<T> {
     type Finished;
 
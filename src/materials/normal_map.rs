@@ -0,0 +1,92 @@
+use rand::prelude::SmallRng;
+
+use crate::{
+    hitable::HitRecord,
+    types::{Ray, Vec3},
+    Material, Texture,
+};
+
+/// Perturbs the shading normal of the wrapped material with a tangent space normal map.
+/// The texture is expected to store the normal as (x, y, z) remapped from [-1, 1] to [0, 1]
+/// with +Z pointing away from the surface, Which is how most normal maps are baked.
+#[derive(Clone)]
+pub struct NormalMap<M, T> {
+    material: M,
+    map: T,
+    strength: f64,
+}
+
+impl<M: Material, T: Texture> NormalMap<M, T> {
+    pub fn new(material: M, map: T) -> Self {
+        Self {
+            material,
+            map,
+            strength: 1.0,
+        }
+    }
+
+    /// strength scales the tangential part of the normal.
+    /// 0.0 disables the map and values above 1.0 exaggerate it
+    #[allow(dead_code)]
+    pub fn with_strength(material: M, map: T, strength: f64) -> Self {
+        Self {
+            material,
+            map,
+            strength,
+        }
+    }
+}
+
+impl<M: Material, T: Texture + Send + Sync> NormalMap<M, T> {
+    fn shading_record<'a>(&self, hit_rec: &HitRecord<'a>) -> HitRecord<'a> {
+        let (tangent, bitangent) = tangent_basis(hit_rec);
+
+        let texel = self.map.value_at(hit_rec) * 2.0 - Vec3::splat(1.0);
+
+        let normal = tangent * (texel.x() * self.strength)
+            + bitangent * (texel.y() * self.strength)
+            + hit_rec.normal * texel.z();
+
+        let mut shading_rec = hit_rec.clone();
+        shading_rec.normal = normal.unit_vector();
+
+        shading_rec
+    }
+}
+
+impl<M: Material, T: Texture + Send + Sync> Material for NormalMap<M, T> {
+    fn scatter(&self, ray: &Ray, hit_rec: &HitRecord, rng: &mut SmallRng) -> (Vec3, Option<Ray>) {
+        self.material
+            .scatter(ray, &self.shading_record(hit_rec), rng)
+    }
+
+    fn scattering_pdf(&self, ray: &Ray, hit_rec: &HitRecord, scattered: &Ray) -> Option<f64> {
+        self.material
+            .scattering_pdf(ray, &self.shading_record(hit_rec), scattered)
+    }
+
+    fn emit(&self, ray: &Ray, hit_rec: &HitRecord) -> Vec3 {
+        self.material.emit(ray, hit_rec)
+    }
+}
+
+/// Orthonormal tangent & bitangent around the (already face oriented) normal
+/// The bitangent keeps the handedness of dp/dv so the green channel isn't mirrored
+fn tangent_basis(hit_rec: &HitRecord) -> (Vec3, Vec3) {
+    let normal = hit_rec.normal;
+    let tangent = hit_rec.dpdu - normal * normal.dot(&hit_rec.dpdu);
+
+    let tangent = if tangent.sq_len() > 1e-16 {
+        tangent.unit_vector()
+    } else {
+        HitRecord::tangent_frame(normal).0
+    };
+
+    let bitangent = normal.cross(&tangent);
+
+    if bitangent.dot(&hit_rec.dpdv) < 0.0 {
+        (tangent, -bitangent)
+    } else {
+        (tangent, bitangent)
+    }
+}
@@ -0,0 +1,166 @@
+use rand::{prelude::SmallRng, Rng};
+
+use crate::{
+    hitable::{volume::sample_distance, HitRecord, Hitable},
+    materials::{random_point_in_unit_sphere, reflect, refract, schlick},
+    types::{Ray, Vec3},
+    Aabb, Material,
+};
+
+// Upper limit on the number of scattering events in a single random walk.
+// Walks that don't escape by then are terminated and contribute nothing
+const MAX_STEPS: usize = 512;
+
+/// Random walk subsurface scattering. Light refracts into the object through a
+/// dielectric interface, Scatters isotropically in a homogeneous medium inside
+/// and refracts back out wherever the walk ends up leaving the object.
+///
+/// The walk has to intersect the object from the inside, So this wraps the
+/// boundary as a Hitable and acts as the material of the surface at the same time.
+/// The material of the boundary itself is ignored.
+pub struct Subsurface<A> {
+    boundary: A,
+    refraction_index: f64,
+    // Extinction & scattering coefficients per color channel
+    sigma_t: Vec3,
+    sigma_s: Vec3,
+}
+
+impl<A: Hitable> Subsurface<A> {
+    /// albedo is the probability of light being scattered instead of absorbed at a particle
+    /// mean_free_path is the average distance light travels between two particles, For each channel
+    pub fn new(boundary: A, refraction_index: f64, albedo: Vec3, mean_free_path: Vec3) -> Self {
+        let sigma_t = Vec3::splat(1.0) / mean_free_path;
+
+        Self {
+            boundary,
+            refraction_index,
+            sigma_t,
+            sigma_s: albedo * sigma_t,
+        }
+    }
+
+    /// Walks through the medium starting at origin until the path refracts back out
+    /// Returns the throughput of the path and the ray leaving the object
+    fn random_walk(
+        &self,
+        mut origin: Vec3,
+        mut direction: Vec3,
+        time: f64,
+        rng: &mut SmallRng,
+    ) -> (Vec3, Option<Ray>) {
+        let mut throughput = Vec3::splat(1.0);
+
+        for _ in 0..MAX_STEPS {
+            let ray = Ray::new(origin, direction, time);
+
+            let exit = match self.boundary.hit(&ray, 0.0001, f64::MAX) {
+                Some(exit) => exit,
+                // Open boundary, Nothing sensible to do here
+                None => return (Vec3::splat(0.0), None),
+            };
+
+            // Sample the distance with the density of one channel, Picked in proportion to
+            // the current throughput so the channels that still carry energy are favoured.
+            // Every channel is then weighted by the combined pdf of the three strategies
+            let channel_pdf = throughput / (throughput.x() + throughput.y() + throughput.z());
+            let density = pick_channel(self.sigma_t, channel_pdf, rng.gen());
+            let distance = sample_distance(rng, density);
+
+            if distance < exit.t {
+                let transmittance = exp(-self.sigma_t * distance);
+                let pdf = sum(channel_pdf * self.sigma_t * transmittance);
+
+                throughput = throughput * self.sigma_s * transmittance / pdf;
+                origin = ray.point_at_parameter(distance);
+                direction = random_point_in_unit_sphere(rng).unit_vector();
+                continue;
+            }
+
+            let transmittance = exp(-self.sigma_t * exit.t);
+            throughput = throughput * transmittance / sum(channel_pdf * transmittance);
+
+            // Reached the surface from the inside. The normal is already facing the ray
+            let cosine = (-direction).dot(&exit.normal).min(1.0);
+            let sin_theta = (1.0 - cosine * cosine).sqrt();
+            let cannot_refract = self.refraction_index * sin_theta > 1.0;
+
+            origin = exit.p;
+
+            if cannot_refract || schlick(cosine, self.refraction_index) > rng.gen::<f64>() {
+                direction = reflect(direction, exit.normal);
+            } else if let Some(refracted) = refract(direction, exit.normal, self.refraction_index) {
+                return (throughput, Some(Ray::new(exit.p, refracted, time)));
+            } else {
+                direction = reflect(direction, exit.normal);
+            }
+        }
+
+        (Vec3::splat(0.0), None)
+    }
+}
+
+impl<A: Hitable + Send + Sync> Hitable for Subsurface<A> {
+    fn hit(&self, ray: &Ray, t_min: f64, t_max: f64) -> Option<HitRecord> {
+        let mut hit = self.boundary.hit(ray, t_min, t_max)?;
+        hit.material = self;
+
+        Some(hit)
+    }
+
+    fn bounding_box(&self, t0: f64, t1: f64) -> Option<Aabb> {
+        self.boundary.bounding_box(t0, t1)
+    }
+}
+
+impl<A: Hitable + Send + Sync> Material for Subsurface<A> {
+    fn scatter(
+        &self,
+        ray_in: &Ray,
+        hit_rec: &HitRecord,
+        rng: &mut SmallRng,
+    ) -> (Vec3, Option<Ray>) {
+        let unit_direction = ray_in.direction.unit_vector();
+
+        // Rays that start inside the object only need to get out
+        if !hit_rec.front_face {
+            return self.random_walk(ray_in.origin, unit_direction, ray_in.time(), rng);
+        }
+
+        let refraction_ratio = 1.0 / self.refraction_index;
+        let cosine = (-unit_direction).dot(&hit_rec.normal).min(1.0);
+
+        if schlick(cosine, refraction_ratio) > rng.gen::<f64>() {
+            let direction = reflect(unit_direction, hit_rec.normal);
+            return (
+                Vec3::splat(1.0),
+                Some(Ray::new(hit_rec.p, direction, ray_in.time())),
+            );
+        }
+
+        match refract(unit_direction, hit_rec.normal, refraction_ratio) {
+            Some(direction) => {
+                self.random_walk(hit_rec.p, direction.unit_vector(), ray_in.time(), rng)
+            }
+            None => (Vec3::splat(0.0), None),
+        }
+    }
+}
+
+fn pick_channel(v: Vec3, pdf: Vec3, sample: f64) -> f64 {
+    if sample < pdf.x() {
+        v.x()
+    } else if sample < pdf.x() + pdf.y() {
+        v.y()
+    } else {
+        v.z()
+    }
+}
+
+fn exp(v: Vec3) -> Vec3 {
+    Vec3::new(v.x().exp(), v.y().exp(), v.z().exp())
+}
+
+fn sum(v: Vec3) -> f64 {
+    v.x() + v.y() + v.z()
+}
@@ -0,0 +1,302 @@
+use std::ops::{Add, Div, Mul, Sub};
+
+use rand::{prelude::SmallRng, Rng};
+
+use crate::{
+    hitable::HitRecord,
+    materials::{reflect, refract},
+    texture::Solid,
+    types::{Ray, Vec3},
+    Material, Texture,
+};
+
+// Wavelengths(in nm) used to represent the red, green & blue channels.
+// Everything below works on a single wavelength at a time, So a spectral
+// renderer would only need to call `film_reflectance` with its own wavelengths
+const RGB_WAVELENGTHS: [f64; 3] = [650.0, 532.0, 450.0];
+
+/// What's underneath the thin film
+#[derive(Clone)]
+pub enum Substrate {
+    /// Transparent material with the given refraction index.
+    /// A soap bubble is a film with air(1.0) on both sides
+    Dielectric(f64),
+    /// Metal with a complex refraction index, eta + i * k for each channel
+    Conductor { eta: Vec3, k: Vec3 },
+}
+
+/// A thin transparent coating like soap bubbles, oil on water or the oxide layer of
+/// anodised metals. Reflections off the top and the bottom of the film interfere
+/// with each other and create the thickness & angle dependent colors.
+#[derive(Clone)]
+pub struct ThinFilm<T = Solid> {
+    // Film thickness in nm is min_thickness + texture * (max_thickness - min_thickness)
+    thickness: T,
+    min_thickness: f64,
+    max_thickness: f64,
+    film_index: f64,
+    substrate: Substrate,
+}
+
+impl ThinFilm<Solid> {
+    /// thickness is in nanometers
+    pub fn new(thickness: f64, film_index: f64, substrate: Substrate) -> Self {
+        Self {
+            thickness: Solid::new(Vec3::splat(0.0)),
+            min_thickness: thickness,
+            max_thickness: thickness,
+            film_index,
+            substrate,
+        }
+    }
+}
+
+impl<T: Texture> ThinFilm<T> {
+    /// Thickness varies over the surface with the value of the texture
+    /// mapped from [0, 1] to [min_thickness, max_thickness] nanometers
+    pub fn with_thickness_texture(
+        thickness: T,
+        min_thickness: f64,
+        max_thickness: f64,
+        film_index: f64,
+        substrate: Substrate,
+    ) -> Self {
+        Self {
+            thickness,
+            min_thickness,
+            max_thickness,
+            film_index,
+            substrate,
+        }
+    }
+
+    fn thickness_at(&self, hit_rec: &HitRecord) -> f64 {
+        let value = self.thickness.value_at(hit_rec);
+        let t = ((value.x() + value.y() + value.z()) / 3.0).clamp(0.0, 1.0);
+
+        self.min_thickness + t * (self.max_thickness - self.min_thickness)
+    }
+}
+
+impl<T: Texture + Send + Sync> Material for ThinFilm<T> {
+    fn scatter(
+        &self,
+        ray_in: &Ray,
+        hit_rec: &HitRecord,
+        rng: &mut SmallRng,
+    ) -> (Vec3, Option<Ray>) {
+        let unit_direction = ray_in.direction.unit_vector();
+        let cosine = (-unit_direction).dot(&hit_rec.normal).clamp(0.0, 1.0);
+        let thickness = self.thickness_at(hit_rec);
+        let reflected = Ray::new(
+            hit_rec.p,
+            reflect(unit_direction, hit_rec.normal),
+            ray_in.time(),
+        );
+
+        match self.substrate {
+            Substrate::Conductor { eta, k } => {
+                let reflectance = rgb(|i, wavelength| {
+                    film_reflectance(
+                        wavelength,
+                        cosine,
+                        thickness,
+                        Complex::real(1.0),
+                        self.film_index,
+                        Complex::new(channel(eta, i), channel(k, i)),
+                    )
+                });
+
+                (reflectance, Some(reflected))
+            }
+
+            Substrate::Dielectric(substrate_index) => {
+                // Coming out of the substrate, The layers are in the reverse order
+                let (outside, inside) = if hit_rec.front_face {
+                    (1.0, substrate_index)
+                } else {
+                    (substrate_index, 1.0)
+                };
+
+                let reflectance = rgb(|_, wavelength| {
+                    film_reflectance(
+                        wavelength,
+                        cosine,
+                        thickness,
+                        Complex::real(outside),
+                        self.film_index,
+                        Complex::real(inside),
+                    )
+                });
+
+                // Pick one of reflection or transmission and weight the result by the
+                // probability of picking it, So both are accounted for in all channels
+                let reflect_probability = ((reflectance.x() + reflectance.y() + reflectance.z())
+                    / 3.0)
+                    .clamp(0.0001, 0.9999);
+
+                if rng.gen::<f64>() < reflect_probability {
+                    return (reflectance / reflect_probability, Some(reflected));
+                }
+
+                // The film is parallel to the surface, So the direction of the transmitted ray
+                // only depends on the media on either side of it
+                match refract(unit_direction, hit_rec.normal, outside / inside) {
+                    Some(direction) => (
+                        (Vec3::splat(1.0) - reflectance) / (1.0 - reflect_probability),
+                        Some(Ray::new(hit_rec.p, direction, ray_in.time())),
+                    ),
+                    None => (Vec3::splat(1.0), Some(reflected)),
+                }
+            }
+        }
+    }
+}
+
+fn rgb(f: impl Fn(usize, f64) -> f64) -> Vec3 {
+    Vec3::new(
+        f(0, RGB_WAVELENGTHS[0]),
+        f(1, RGB_WAVELENGTHS[1]),
+        f(2, RGB_WAVELENGTHS[2]),
+    )
+}
+
+fn channel(v: Vec3, index: usize) -> f64 {
+    match index {
+        0 => v.x(),
+        1 => v.y(),
+        _ => v.z(),
+    }
+}
+
+/// Reflectance of unpolarized light off a film of the given thickness(nm) & refraction index
+/// sitting between the outside medium and the substrate. This is the Airy summation of all the
+/// light bouncing back & forth inside the film. See, https://en.wikipedia.org/wiki/Thin-film_interference
+pub fn film_reflectance(
+    wavelength: f64,
+    cos_outside: f64,
+    thickness: f64,
+    outside: Complex,
+    film_index: f64,
+    substrate: Complex,
+) -> f64 {
+    let film = Complex::real(film_index);
+    let cos_outside = Complex::real(cos_outside);
+
+    // Snell's law, n1 * sin(theta1) = n2 * sin(theta2), With complex angles for total
+    // internal reflection and absorbing media
+    let sin2_outside = Complex::real(1.0) - cos_outside * cos_outside;
+    let cos_in = |n: Complex| {
+        let sin_ratio = outside / n;
+        (Complex::real(1.0) - sin_ratio * sin_ratio * sin2_outside).sqrt()
+    };
+    let cos_film = cos_in(film);
+    let cos_substrate = cos_in(substrate);
+
+    // Phase difference between two consecutive reflections inside the film
+    let phase =
+        Complex::real(4.0 * std::f64::consts::PI * thickness / wavelength) * film * cos_film;
+    let phase_shift = (phase * Complex::new(0.0, 1.0)).exp();
+
+    let airy = |r12: Complex, r23: Complex| {
+        let r = (r12 + r23 * phase_shift) / (Complex::real(1.0) + r12 * r23 * phase_shift);
+        r.norm_sqr().min(1.0)
+    };
+
+    let rs = airy(
+        fresnel_s(outside, cos_outside, film, cos_film),
+        fresnel_s(film, cos_film, substrate, cos_substrate),
+    );
+    let rp = airy(
+        fresnel_p(outside, cos_outside, film, cos_film),
+        fresnel_p(film, cos_film, substrate, cos_substrate),
+    );
+
+    (rs + rp) / 2.0
+}
+
+// Fresnel amplitude coefficients for s & p polarized light
+fn fresnel_s(n1: Complex, cos1: Complex, n2: Complex, cos2: Complex) -> Complex {
+    (n1 * cos1 - n2 * cos2) / (n1 * cos1 + n2 * cos2)
+}
+
+fn fresnel_p(n1: Complex, cos1: Complex, n2: Complex, cos2: Complex) -> Complex {
+    (n2 * cos1 - n1 * cos2) / (n2 * cos1 + n1 * cos2)
+}
+
+/// Just enough complex arithmetic for the fresnel equations
+#[derive(Debug, Copy, Clone)]
+pub struct Complex {
+    re: f64,
+    im: f64,
+}
+
+impl Complex {
+    pub const fn new(re: f64, im: f64) -> Self {
+        Self { re, im }
+    }
+
+    pub const fn real(re: f64) -> Self {
+        Self { re, im: 0.0 }
+    }
+
+    fn norm_sqr(self) -> f64 {
+        self.re * self.re + self.im * self.im
+    }
+
+    // Principal square root, The real part is never negative & the imaginary part has the
+    // sign of the input's. Refracted cosines rely on that branch, Past the critical angle
+    // the input is a negative real so the cosine comes out as +i times something & the
+    // phase shift through the film decays instead of blowing up
+    fn sqrt(self) -> Self {
+        let norm = self.norm_sqr().sqrt();
+        let re = ((norm + self.re) / 2.0).sqrt();
+        let im = ((norm - self.re) / 2.0).sqrt();
+
+        Self::new(re, if self.im < 0.0 { -im } else { im })
+    }
+
+    fn exp(self) -> Self {
+        let magnitude = self.re.exp();
+        Self::new(magnitude * self.im.cos(), magnitude * self.im.sin())
+    }
+}
+
+impl Add for Complex {
+    type Output = Complex;
+
+    fn add(self, o: Complex) -> Complex {
+        Complex::new(self.re + o.re, self.im + o.im)
+    }
+}
+
+impl Sub for Complex {
+    type Output = Complex;
+
+    fn sub(self, o: Complex) -> Complex {
+        Complex::new(self.re - o.re, self.im - o.im)
+    }
+}
+
+impl Mul for Complex {
+    type Output = Complex;
+
+    fn mul(self, o: Complex) -> Complex {
+        Complex::new(
+            self.re * o.re - self.im * o.im,
+            self.re * o.im + self.im * o.re,
+        )
+    }
+}
+
+impl Div for Complex {
+    type Output = Complex;
+
+    fn div(self, o: Complex) -> Complex {
+        let denominator = o.norm_sqr();
+        Complex::new(
+            (self.re * o.re + self.im * o.im) / denominator,
+            (self.im * o.re - self.re * o.im) / denominator,
+        )
+    }
+}
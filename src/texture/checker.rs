@@ -1,25 +1,116 @@
-use crate::{types::Vec3, Texture};
+use crate::{hitable::HitRecord, texture::Space, types::Vec3, Texture};
 
+/// 3D checker pattern, sin(f * x) * sin(f * y) * sin(f * z) split into two textures
+/// by its sign. f is the frequency & squares are PI / f units wide
 #[derive(Clone)]
 pub struct Checker<T: Texture + Clone> {
     odd: T,
     even: T,
+    frequency: f64,
+    space: Space,
 }
 
 impl<T: Texture + Clone> Checker<T> {
     pub fn new(even: T, odd: T) -> Self {
-        Self { odd, even }
+        Self {
+            odd,
+            even,
+            frequency: 10.0,
+            space: Space::World,
+        }
+    }
+
+    pub fn with_frequency(mut self, frequency: f64) -> Self {
+        self.frequency = frequency;
+        self
+    }
+
+    /// Computes the pattern from the position on the object before it was instanced,
+    /// So the squares move & rotate along with it
+    pub fn in_object_space(mut self) -> Self {
+        self.space = Space::Object;
+        self
+    }
+
+    fn pick(&self, p: Vec3) -> &T {
+        let f = self.frequency;
+        let sine_wave = f64::sin(f * p.x()) * f64::sin(f * p.y()) * f64::sin(f * p.z());
+
+        if sine_wave < 0.0 {
+            &self.odd
+        } else {
+            &self.even
+        }
     }
 }
 
 impl<T: Texture + Clone> Texture for Checker<T> {
     fn value(&self, u: f64, v: f64, p: Vec3) -> Vec3 {
-        let sine_wave = f64::sin(10.0 * p.x()) * f64::sin(10.0 * p.y()) * f64::sin(10.0 * p.z());
+        self.pick(p).value(u, v, p)
+    }
 
-        if sine_wave < 0.0 {
-            self.odd.value(u, v, p)
+    fn value_at(&self, hit_rec: &HitRecord) -> Vec3 {
+        self.pick(self.space.point(hit_rec)).value_at(hit_rec)
+    }
+
+    fn alpha(&self, u: f64, v: f64, p: Vec3) -> f64 {
+        self.pick(p).alpha(u, v, p)
+    }
+
+    fn alpha_at(&self, hit_rec: &HitRecord) -> f64 {
+        self.pick(self.space.point(hit_rec)).alpha_at(hit_rec)
+    }
+}
+
+/// Checker pattern in texture coordinates, Which follows the surface wherever it goes.
+/// repeat is the number of squares along u & v
+#[derive(Clone)]
+pub struct UvChecker<T: Texture + Clone> {
+    odd: T,
+    even: T,
+    repeat: (f64, f64),
+}
+
+impl<T: Texture + Clone> UvChecker<T> {
+    pub fn new(even: T, odd: T) -> Self {
+        Self {
+            odd,
+            even,
+            repeat: (16.0, 8.0),
+        }
+    }
+
+    pub fn with_repeat(mut self, repeat: (f64, f64)) -> Self {
+        self.repeat = repeat;
+        self
+    }
+
+    fn pick(&self, u: f64, v: f64) -> &T {
+        let i = (u * self.repeat.0).floor() as i64;
+        let j = (v * self.repeat.1).floor() as i64;
+
+        if (i + j).rem_euclid(2) == 0 {
+            &self.even
         } else {
-            self.even.value(u, v, p)
+            &self.odd
         }
     }
 }
+
+impl<T: Texture + Clone> Texture for UvChecker<T> {
+    fn value(&self, u: f64, v: f64, p: Vec3) -> Vec3 {
+        self.pick(u, v).value(u, v, p)
+    }
+
+    fn value_at(&self, hit_rec: &HitRecord) -> Vec3 {
+        self.pick(hit_rec.u, hit_rec.v).value_at(hit_rec)
+    }
+
+    fn alpha(&self, u: f64, v: f64, p: Vec3) -> f64 {
+        self.pick(u, v).alpha(u, v, p)
+    }
+
+    fn alpha_at(&self, hit_rec: &HitRecord) -> f64 {
+        self.pick(hit_rec.u, hit_rec.v).alpha_at(hit_rec)
+    }
+}
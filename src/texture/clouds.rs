@@ -0,0 +1,60 @@
+use crate::{
+    hitable::HitRecord,
+    texture::{noise::Noise, ColorRamp, Space},
+    types::Vec3,
+    Texture,
+};
+
+/// Puffy clouds. Only the parts of the noise above coverage show up, Stretched over the
+/// whole ramp so that 0 is clear sky & 1 is the thickest part of a cloud
+#[derive(Clone)]
+pub struct Clouds<N: Noise> {
+    noise: N,
+    ramp: ColorRamp,
+    // Fraction of the sky that's clear, In [0, 1)
+    coverage: f64,
+    scale: f64,
+    space: Space,
+}
+
+impl<N: Noise> Clouds<N> {
+    pub fn new(noise: N, ramp: ColorRamp) -> Self {
+        Self {
+            noise,
+            ramp,
+            coverage: 0.5,
+            scale: 1.0,
+            space: Space::World,
+        }
+    }
+
+    pub fn with_coverage(mut self, coverage: f64) -> Self {
+        self.coverage = coverage;
+        self
+    }
+
+    pub fn with_scale(mut self, scale: f64) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Pattern sticks to the object instead of the object moving through it
+    #[allow(dead_code)]
+    pub fn in_object_space(mut self) -> Self {
+        self.space = Space::Object;
+        self
+    }
+}
+
+impl<N: Noise> Texture for Clouds<N> {
+    fn value(&self, _u: f64, _v: f64, p: Vec3) -> Vec3 {
+        let density = 0.5 * (1.0 + self.noise.noise(p * self.scale));
+        let t = ((density - self.coverage) / (1.0 - self.coverage)).clamp(0.0, 1.0);
+
+        self.ramp.color(t)
+    }
+
+    fn value_at(&self, hit_rec: &HitRecord) -> Vec3 {
+        self.value(hit_rec.u, hit_rec.v, self.space.point(hit_rec))
+    }
+}
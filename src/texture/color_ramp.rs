@@ -0,0 +1,36 @@
+use crate::types::Vec3;
+
+/// Maps a value in [0, 1] to a color by blending between the colors of the closest stops.
+/// Values outside of the stops get the color of the first or last one
+#[derive(Clone)]
+pub struct ColorRamp {
+    stops: Vec<(f64, Vec3)>,
+}
+
+impl ColorRamp {
+    /// stops are (position, color) pairs, They don't need to be sorted
+    pub fn new(mut stops: Vec<(f64, Vec3)>) -> Self {
+        assert!(!stops.is_empty(), "color ramp needs at least one stop");
+
+        stops.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        Self { stops }
+    }
+
+    pub fn color(&self, t: f64) -> Vec3 {
+        let i = self.stops.partition_point(|&(position, _)| position <= t);
+
+        if i == 0 {
+            return self.stops[0].1;
+        }
+        if i == self.stops.len() {
+            return self.stops[i - 1].1;
+        }
+
+        let (p0, c0) = self.stops[i - 1];
+        let (p1, c1) = self.stops[i];
+        let f = (t - p0) / (p1 - p0);
+
+        c0 * (1.0 - f) + c1 * f
+    }
+}
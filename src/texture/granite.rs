@@ -0,0 +1,55 @@
+use crate::{
+    hitable::HitRecord,
+    texture::{noise::Worley, ColorRamp, Space},
+    types::Vec3,
+    Texture,
+};
+
+/// Grains of different colors packed together. Every cell of the Worley noise is a grain
+/// & gets its color from the ramp, The edges between them are darkened
+#[derive(Clone)]
+pub struct Granite {
+    cells: Worley,
+    ramp: ColorRamp,
+    // Number of grains per unit
+    scale: f64,
+    space: Space,
+}
+
+impl Granite {
+    pub fn new(cells: Worley, ramp: ColorRamp) -> Self {
+        Self {
+            cells,
+            ramp,
+            scale: 1.0,
+            space: Space::World,
+        }
+    }
+
+    pub fn with_scale(mut self, scale: f64) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Pattern sticks to the object instead of the object moving through it
+    #[allow(dead_code)]
+    pub fn in_object_space(mut self) -> Self {
+        self.space = Space::Object;
+        self
+    }
+}
+
+impl Texture for Granite {
+    fn value(&self, _u: f64, _v: f64, p: Vec3) -> Vec3 {
+        let cell = self.cells.cell(p * self.scale);
+
+        // f2 - f1 is 0 on the edges between 2 cells
+        let edge = ((cell.f2 - cell.f1) / 0.1).min(1.0);
+
+        self.ramp.color(cell.id) * (0.6 + 0.4 * edge)
+    }
+
+    fn value_at(&self, hit_rec: &HitRecord) -> Vec3 {
+        self.value(hit_rec.u, hit_rec.v, self.space.point(hit_rec))
+    }
+}
@@ -1,56 +1,350 @@
-use image::{error::ImageError, io::Reader as ImageReader};
+use image::{
+    codecs::hdr::HdrDecoder, error::ImageError, io::Reader as ImageReader, ColorType, DynamicImage,
+    ImageFormat, Rgb32FImage,
+};
 
-use crate::{types::Vec3, Texture};
+use crate::{hitable::HitRecord, types::Vec3, Texture};
+
+type Rgba = [f64; 4];
+
+/// What happens to texture coordinates outside of [0, 1]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Wrap {
+    /// Repeat the texels on the edges
+    Clamp,
+    /// Tile the image
+    Repeat,
+    /// Tile the image, Flipping every other tile
+    #[allow(dead_code)]
+    Mirror,
+}
+
+/// How texels around a texture coordinate are combined
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Filter {
+    Nearest,
+    Bilinear,
+    /// Catmull-Rom spline through the 4x4 texels around the point
+    Bicubic,
+}
+
+#[derive(Clone)]
+struct MipLevel {
+    width: usize,
+    height: usize,
+    texels: Vec<Rgba>,
+}
+
+impl MipLevel {
+    fn texel(&self, i: i64, j: i64, wrap: Wrap) -> Rgba {
+        let i = wrap_index(i, self.width, wrap);
+        let j = wrap_index(j, self.height, wrap);
+
+        self.texels[j * self.width + i]
+    }
+
+    /// Halves the resolution with a box filter
+    fn downsample(&self) -> Self {
+        let width = (self.width / 2).max(1);
+        let height = (self.height / 2).max(1);
+
+        let mut texels = Vec::with_capacity(width * height);
+        for j in 0..height as i64 {
+            for i in 0..width as i64 {
+                let mut acc = [0.0; 4];
+                for (di, dj) in [(0, 0), (1, 0), (0, 1), (1, 1)] {
+                    let texel = self.texel(2 * i + di, 2 * j + dj, Wrap::Clamp);
+                    for (a, t) in acc.iter_mut().zip(texel.iter()) {
+                        *a += t / 4.0;
+                    }
+                }
+                texels.push(acc);
+            }
+        }
+
+        Self {
+            width,
+            height,
+            texels,
+        }
+    }
+
+    // (x, y) is in texel space with (0, 0) at the top left corner of the image
+    fn sample(&self, x: f64, y: f64, filter: Filter, wrap: Wrap) -> Rgba {
+        match filter {
+            Filter::Nearest => self.texel(x.floor() as i64, y.floor() as i64, wrap),
+
+            Filter::Bilinear => {
+                // Texel centers are at half integer coordinates
+                let (x, y) = (x - 0.5, y - 0.5);
+                let (i, j) = (x.floor() as i64, y.floor() as i64);
+                let (fx, fy) = (x - x.floor(), y - y.floor());
+
+                let top = lerp(self.texel(i, j, wrap), self.texel(i + 1, j, wrap), fx);
+                let bottom = lerp(
+                    self.texel(i, j + 1, wrap),
+                    self.texel(i + 1, j + 1, wrap),
+                    fx,
+                );
+
+                lerp(top, bottom, fy)
+            }
+
+            Filter::Bicubic => {
+                let (x, y) = (x - 0.5, y - 0.5);
+                let (i, j) = (x.floor() as i64, y.floor() as i64);
+                let wx = catmull_rom_weights(x - x.floor());
+                let wy = catmull_rom_weights(y - y.floor());
+
+                let mut acc = [0.0; 4];
+                for (dj, wy) in wy.iter().enumerate() {
+                    for (di, wx) in wx.iter().enumerate() {
+                        let texel = self.texel(i + di as i64 - 1, j + dj as i64 - 1, wrap);
+                        for (a, t) in acc.iter_mut().zip(texel.iter()) {
+                            *a += t * wx * wy;
+                        }
+                    }
+                }
+
+                // The spline overshoots around sharp edges
+                acc.map(|c| c.max(0.0))
+            }
+        }
+    }
+}
 
 #[derive(Clone)]
 pub struct ImageTexture {
-    image: Vec<u8>,
-    // (width, height)
-    dimensions: (u32, u32),
-    bytes_per_scanline: u32,
-    bytes_per_pixel: u32,
+    // levels[0] is the image as is and each level after that is half the size
+    // of the previous one. There is only one level unless mipmaps are enabled
+    levels: Vec<MipLevel>,
+    filter: Filter,
+    wrap: Wrap,
+    // Applied to the texture coordinates before the lookup, (u, v) * scale + offset
+    uv_scale: (f64, f64),
+    uv_offset: (f64, f64),
 }
 
 impl ImageTexture {
+    /// Loads a color texture. 8 & 16 bit images are assumed to be sRGB encoded
+    /// and are converted to linear values. Floating point images like .hdr files
+    /// are linear already and are used as is
     #[allow(dead_code)]
     pub fn from_filename(filename: &str) -> Result<Self, ImageError> {
-        let img = ImageReader::open(filename)?.decode()?;
-        let img = img.to_rgb8();
+        Self::load(filename, false)
+    }
+
+    /// Loads a texture that holds data instead of colors, Like normal, roughness
+    /// or height maps. The values are used as is without any color space conversion
+    pub fn from_filename_linear(filename: &str) -> Result<Self, ImageError> {
+        Self::load(filename, true)
+    }
+
+    /// Texture from linear colors stored row by row, Starting at the top left corner
+    pub fn from_pixels(width: usize, height: usize, pixels: &[Vec3]) -> Self {
+        assert_eq!(pixels.len(), width * height);
+
+        let texels = pixels.iter().map(|p| [p.x(), p.y(), p.z(), 1.0]).collect();
+
+        Self::from_texels(width, height, texels)
+    }
+
+    fn load(filename: &str, linear: bool) -> Result<Self, ImageError> {
+        let reader = ImageReader::open(filename)?;
+
+        let img = if reader.format() == Some(ImageFormat::Hdr) {
+            // The generic decoder tone maps .hdr files down to 8 bits, Which would throw
+            // away everything brighter than 1.0
+            let decoder = HdrDecoder::new(reader.into_inner())?;
+            let meta = decoder.metadata();
+            let pixels = decoder.read_image_hdr()?;
+
+            DynamicImage::ImageRgb32F(Rgb32FImage::from_fn(meta.width, meta.height, |x, y| {
+                pixels[(y * meta.width + x) as usize]
+            }))
+        } else {
+            reader.decode()?
+        };
+
+        let linear = linear || matches!(img.color(), ColorType::Rgb32F | ColorType::Rgba32F);
+
+        // Images without an alpha channel are treated as fully opaque
+        // Integer images are normalized to [0, 1] with their full precision
+        let img = img.to_rgba32f();
+
+        let (width, height) = img.dimensions();
 
-        let (width, _) = img.dimensions();
+        let texels = img
+            .pixels()
+            .map(|pixel| {
+                let [r, g, b, a] = pixel.0.map(|c| c as f64);
+                if linear {
+                    [r, g, b, a]
+                } else {
+                    [srgb_to_linear(r), srgb_to_linear(g), srgb_to_linear(b), a]
+                }
+            })
+            .collect();
 
-        let bytes_per_pixel = 3;
+        Ok(Self::from_texels(width as usize, height as usize, texels))
+    }
+
+    fn from_texels(width: usize, height: usize, texels: Vec<Rgba>) -> Self {
+        Self {
+            levels: vec![MipLevel {
+                width,
+                height,
+                texels,
+            }],
+            filter: Filter::Nearest,
+            wrap: Wrap::Clamp,
+            uv_scale: (1.0, 1.0),
+            uv_offset: (0.0, 0.0),
+        }
+    }
+
+    /// (width, height) of the image in pixels
+    pub fn dimensions(&self) -> (usize, usize) {
+        (self.levels[0].width, self.levels[0].height)
+    }
+
+    pub fn with_filter(mut self, filter: Filter) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    pub fn with_wrap(mut self, wrap: Wrap) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    /// Tiles the texture scale times over the surface, Shifted by offset
+    pub fn with_uv_transform(mut self, scale: (f64, f64), offset: (f64, f64)) -> Self {
+        self.uv_scale = scale;
+        self.uv_offset = offset;
+        self
+    }
+
+    /// Builds the mip pyramid so lookups covering many texels return their average
+    /// instead of a single one of them, Which is what causes aliasing far away
+    pub fn with_mipmaps(mut self) -> Self {
+        self.levels.truncate(1);
+
+        loop {
+            let last = self.levels.last().unwrap();
+            if last.width == 1 && last.height == 1 {
+                break;
+            }
+            let next = last.downsample();
+            self.levels.push(next);
+        }
+
+        self
+    }
+
+    fn sample(&self, u: f64, v: f64, level: usize) -> Rgba {
+        let u = u * self.uv_scale.0 + self.uv_offset.0;
+        let v = v * self.uv_scale.1 + self.uv_offset.1;
+
+        let level = &self.levels[level];
+
+        // Images are stored top to bottom but v goes from bottom to top
+        let x = u * level.width as f64;
+        let y = (1.0 - v) * level.height as f64;
+
+        level.sample(x, y, self.filter, self.wrap)
+    }
+
+    /// Trilinear lookup between the two mip levels closest to the footprint
+    fn sample_footprint(&self, hit_rec: &HitRecord) -> Rgba {
+        let (dpdu, dpdv) = (hit_rec.dpdu.length(), hit_rec.dpdv.length());
+
+        if self.levels.len() == 1 || hit_rec.footprint <= 0.0 || dpdu == 0.0 || dpdv == 0.0 {
+            return self.sample(hit_rec.u, hit_rec.v, 0);
+        }
+
+        // Size of the footprint in texture space and then in texels
+        let du = hit_rec.footprint / dpdu * self.uv_scale.0.abs();
+        let dv = hit_rec.footprint / dpdv * self.uv_scale.1.abs();
+
+        let base = &self.levels[0];
+        let texels = (du * base.width as f64).max(dv * base.height as f64);
 
-        Ok(Self {
-            image: img.to_vec(),
-            dimensions: img.dimensions(),
-            bytes_per_scanline: bytes_per_pixel * width,
-            bytes_per_pixel,
-        })
+        let max_level = (self.levels.len() - 1) as f64;
+        let lod = texels.log2().clamp(0.0, max_level);
+
+        let level = lod.floor() as usize;
+        let fine = self.sample(hit_rec.u, hit_rec.v, level);
+
+        if lod == lod.floor() {
+            return fine;
+        }
+
+        let coarse = self.sample(hit_rec.u, hit_rec.v, level + 1);
+        lerp(fine, coarse, lod - lod.floor())
     }
 }
 
 impl Texture for ImageTexture {
     fn value(&self, u: f64, v: f64, _p: Vec3) -> Vec3 {
-        let (width, height) = self.dimensions;
+        let [r, g, b, _] = self.sample(u, v, 0);
 
-        let u = u.clamp(0.0, 1.0);
-        let v = 1.0 - v.clamp(0.0, 1.0);
+        Vec3::new(r, g, b)
+    }
 
-        let i = (u * width as f64) as u32;
-        let j = (v * height as f64) as u32;
+    fn value_at(&self, hit_rec: &HitRecord) -> Vec3 {
+        let [r, g, b, _] = self.sample_footprint(hit_rec);
 
-        let i = i.clamp(0, width - 1);
-        let j = j.clamp(0, height - 1);
+        Vec3::new(r, g, b)
+    }
 
-        let color_scale = 1.0 / 255.0;
+    fn alpha(&self, u: f64, v: f64, _p: Vec3) -> f64 {
+        self.sample(u, v, 0)[3]
+    }
+}
+
+fn wrap_index(i: i64, size: usize, wrap: Wrap) -> usize {
+    let size = size as i64;
 
-        let pixel = (j * self.bytes_per_scanline + i * self.bytes_per_pixel) as usize;
+    let i = match wrap {
+        Wrap::Clamp => i.clamp(0, size - 1),
+        Wrap::Repeat => i.rem_euclid(size),
+        Wrap::Mirror => {
+            let i = i.rem_euclid(2 * size);
+            if i < size {
+                i
+            } else {
+                2 * size - 1 - i
+            }
+        }
+    };
+
+    i as usize
+}
 
-        Vec3::new(
-            color_scale * (self.image[pixel] as f64),
-            color_scale * (self.image[pixel + 1] as f64),
-            color_scale * (self.image[pixel + 2] as f64),
-        )
+// See, https://en.wikipedia.org/wiki/SRGB#From_sRGB_to_CIE_XYZ
+fn srgb_to_linear(c: f64) -> f64 {
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
     }
 }
+
+fn lerp(a: Rgba, b: Rgba, t: f64) -> Rgba {
+    [
+        a[0] + (b[0] - a[0]) * t,
+        a[1] + (b[1] - a[1]) * t,
+        a[2] + (b[2] - a[2]) * t,
+        a[3] + (b[3] - a[3]) * t,
+    ]
+}
+
+fn catmull_rom_weights(t: f64) -> [f64; 4] {
+    [
+        t * (-0.5 + t * (1.0 - 0.5 * t)),
+        1.0 + t * t * (-2.5 + 1.5 * t),
+        t * (0.5 + t * (2.0 - 1.5 * t)),
+        t * t * (-0.5 + 0.5 * t),
+    ]
+}
@@ -0,0 +1,68 @@
+use crate::{
+    hitable::HitRecord,
+    texture::{noise::Noise, ColorRamp, Space},
+    types::Vec3,
+    Texture,
+};
+
+/// Bands along an axis, Bent by noise. The generalised version of `PerlinNoise`
+#[derive(Clone)]
+pub struct Marble<N: Noise> {
+    noise: N,
+    ramp: ColorRamp,
+    // Direction & frequency of the bands
+    axis: Vec3,
+    // How much the noise bends the bands
+    distortion: f64,
+    // Scale of the noise
+    scale: f64,
+    space: Space,
+}
+
+impl<N: Noise> Marble<N> {
+    pub fn new(noise: N, ramp: ColorRamp) -> Self {
+        Self {
+            noise,
+            ramp,
+            axis: Vec3::new(0.0, 0.0, 1.0),
+            distortion: 10.0,
+            scale: 1.0,
+            space: Space::World,
+        }
+    }
+
+    /// Bands are perpendicular to axis, & there are |axis| / 2PI of them per unit
+    pub fn with_axis(mut self, axis: Vec3) -> Self {
+        self.axis = axis;
+        self
+    }
+
+    pub fn with_distortion(mut self, distortion: f64) -> Self {
+        self.distortion = distortion;
+        self
+    }
+
+    pub fn with_scale(mut self, scale: f64) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Pattern sticks to the object instead of the object moving through it
+    pub fn in_object_space(mut self) -> Self {
+        self.space = Space::Object;
+        self
+    }
+}
+
+impl<N: Noise> Texture for Marble<N> {
+    fn value(&self, _u: f64, _v: f64, p: Vec3) -> Vec3 {
+        let turbulence = self.noise.noise(p * self.scale).abs();
+        let t = 0.5 * (1.0 + (p.dot(&self.axis) + self.distortion * turbulence).sin());
+
+        self.ramp.color(t)
+    }
+
+    fn value_at(&self, hit_rec: &HitRecord) -> Vec3 {
+        self.value(hit_rec.u, hit_rec.v, self.space.point(hit_rec))
+    }
+}
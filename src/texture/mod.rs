@@ -1,17 +1,85 @@
 mod checker;
+mod clouds;
+mod color_ramp;
+mod granite;
 mod image_texture;
-mod perlin;
+mod marble;
+pub mod nodes;
+pub mod noise;
 mod perlin_noise;
 mod solid;
+mod wood;
 
-pub use checker::Checker;
-pub use image_texture::ImageTexture;
-pub use perlin::Perlin;
+pub use checker::{Checker, UvChecker};
+pub use clouds::Clouds;
+pub use color_ramp::ColorRamp;
+pub use granite::Granite;
+pub use image_texture::{Filter, ImageTexture, Wrap};
+pub use marble::Marble;
+pub use noise::Perlin;
 pub use perlin_noise::PerlinNoise;
 pub use solid::Solid;
+pub use wood::Wood;
 
-use crate::types::Vec3;
+use std::sync::Arc;
+
+use crate::{hitable::HitRecord, types::Vec3};
 
 pub trait Texture {
     fn value(&self, u: f64, v: f64, p: Vec3) -> Vec3;
+
+    /// Same as `value` but with everything known about the hit point
+    /// Textures that can make use of the footprint or tangents override this
+    fn value_at(&self, hit_rec: &HitRecord) -> Vec3 {
+        self.value(hit_rec.u, hit_rec.v, hit_rec.p)
+    }
+
+    /// Opacity at the given point. 1.0 is fully opaque and 0.0 is fully transparent
+    fn alpha(&self, _u: f64, _v: f64, _p: Vec3) -> f64 {
+        1.0
+    }
+
+    /// Same as `alpha` but with everything known about the hit point, Like `value_at`
+    fn alpha_at(&self, hit_rec: &HitRecord) -> f64 {
+        self.alpha(hit_rec.u, hit_rec.v, hit_rec.p)
+    }
+}
+
+/// Space that a 3D texture is computed in
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Space {
+    /// Pattern stays in place while objects move through it
+    World,
+    /// Pattern sticks to the object it's on, See `HitRecord::local_p`
+    Object,
+}
+
+impl Space {
+    pub fn point(self, hit_rec: &HitRecord) -> Vec3 {
+        match self {
+            Space::World => hit_rec.p,
+            Space::Object => hit_rec.local_p,
+        }
+    }
+}
+
+pub trait ParallelTexture: Texture + Send + Sync {}
+impl<T: Texture + Send + Sync> ParallelTexture for T {}
+
+impl<T: Texture + ?Sized> Texture for Arc<T> {
+    fn value(&self, u: f64, v: f64, p: Vec3) -> Vec3 {
+        self.as_ref().value(u, v, p)
+    }
+
+    fn value_at(&self, hit_rec: &HitRecord) -> Vec3 {
+        self.as_ref().value_at(hit_rec)
+    }
+
+    fn alpha(&self, u: f64, v: f64, p: Vec3) -> f64 {
+        self.as_ref().alpha(u, v, p)
+    }
+
+    fn alpha_at(&self, hit_rec: &HitRecord) -> f64 {
+        self.as_ref().alpha_at(hit_rec)
+    }
 }
@@ -0,0 +1,118 @@
+use std::sync::Arc;
+
+use crate::{
+    hitable::HitRecord,
+    texture::{nodes::luminance, ParallelTexture},
+    types::Vec3,
+    Texture,
+};
+
+/// Blends from a to b by factor. Black factor is all a & white is all b
+#[derive(Clone)]
+pub struct Mix {
+    a: Arc<dyn ParallelTexture>,
+    b: Arc<dyn ParallelTexture>,
+    factor: Arc<dyn ParallelTexture>,
+}
+
+impl Mix {
+    pub fn new(
+        a: Arc<dyn ParallelTexture>,
+        b: Arc<dyn ParallelTexture>,
+        factor: Arc<dyn ParallelTexture>,
+    ) -> Self {
+        Self { a, b, factor }
+    }
+}
+
+impl Texture for Mix {
+    fn value(&self, u: f64, v: f64, p: Vec3) -> Vec3 {
+        let t = luminance(self.factor.value(u, v, p)).clamp(0.0, 1.0);
+
+        self.a.value(u, v, p) * (1.0 - t) + self.b.value(u, v, p) * t
+    }
+
+    fn value_at(&self, hit_rec: &HitRecord) -> Vec3 {
+        let t = luminance(self.factor.value_at(hit_rec)).clamp(0.0, 1.0);
+
+        self.a.value_at(hit_rec) * (1.0 - t) + self.b.value_at(hit_rec) * t
+    }
+
+    fn alpha(&self, u: f64, v: f64, p: Vec3) -> f64 {
+        let t = luminance(self.factor.value(u, v, p)).clamp(0.0, 1.0);
+
+        self.a.alpha(u, v, p) * (1.0 - t) + self.b.alpha(u, v, p) * t
+    }
+
+    fn alpha_at(&self, hit_rec: &HitRecord) -> f64 {
+        let t = luminance(self.factor.value_at(hit_rec)).clamp(0.0, 1.0);
+
+        self.a.alpha_at(hit_rec) * (1.0 - t) + self.b.alpha_at(hit_rec) * t
+    }
+}
+
+#[derive(Clone)]
+pub struct Add {
+    a: Arc<dyn ParallelTexture>,
+    b: Arc<dyn ParallelTexture>,
+}
+
+impl Add {
+    pub fn new(a: Arc<dyn ParallelTexture>, b: Arc<dyn ParallelTexture>) -> Self {
+        Self { a, b }
+    }
+}
+
+impl Texture for Add {
+    fn value(&self, u: f64, v: f64, p: Vec3) -> Vec3 {
+        self.a.value(u, v, p) + self.b.value(u, v, p)
+    }
+
+    fn value_at(&self, hit_rec: &HitRecord) -> Vec3 {
+        self.a.value_at(hit_rec) + self.b.value_at(hit_rec)
+    }
+}
+
+#[derive(Clone)]
+pub struct Multiply {
+    a: Arc<dyn ParallelTexture>,
+    b: Arc<dyn ParallelTexture>,
+}
+
+impl Multiply {
+    pub fn new(a: Arc<dyn ParallelTexture>, b: Arc<dyn ParallelTexture>) -> Self {
+        Self { a, b }
+    }
+}
+
+impl Texture for Multiply {
+    fn value(&self, u: f64, v: f64, p: Vec3) -> Vec3 {
+        self.a.value(u, v, p) * self.b.value(u, v, p)
+    }
+
+    fn value_at(&self, hit_rec: &HitRecord) -> Vec3 {
+        self.a.value_at(hit_rec) * self.b.value_at(hit_rec)
+    }
+}
+
+/// 1 - input, For every channel
+#[derive(Clone)]
+pub struct Invert {
+    input: Arc<dyn ParallelTexture>,
+}
+
+impl Invert {
+    pub fn new(input: Arc<dyn ParallelTexture>) -> Self {
+        Self { input }
+    }
+}
+
+impl Texture for Invert {
+    fn value(&self, u: f64, v: f64, p: Vec3) -> Vec3 {
+        Vec3::splat(1.0) - self.input.value(u, v, p)
+    }
+
+    fn value_at(&self, hit_rec: &HitRecord) -> Vec3 {
+        Vec3::splat(1.0) - self.input.value_at(hit_rec)
+    }
+}
@@ -0,0 +1,19 @@
+//! Textures that combine or modify other textures. Inputs are `Arc<dyn ParallelTexture>`,
+//! So nodes of any type can be plugged into each other to build arbitrary graphs
+
+mod math;
+mod ramp;
+mod transform;
+mod triplanar;
+
+pub use math::{Add, Invert, Mix, Multiply};
+pub use ramp::Ramp;
+pub use transform::{PointTransform, UvTransform};
+pub use triplanar::Triplanar;
+
+use crate::types::Vec3;
+
+/// Single value used when a color texture drives a scalar input like a mix factor
+pub(crate) fn luminance(color: Vec3) -> f64 {
+    0.2126 * color.x() + 0.7152 * color.y() + 0.0722 * color.z()
+}
@@ -0,0 +1,31 @@
+use std::sync::Arc;
+
+use crate::{
+    hitable::HitRecord,
+    texture::{nodes::luminance, ColorRamp, ParallelTexture},
+    types::Vec3,
+    Texture,
+};
+
+/// Colors a scalar texture, Like a black & white noise, With a color ramp
+#[derive(Clone)]
+pub struct Ramp {
+    input: Arc<dyn ParallelTexture>,
+    ramp: ColorRamp,
+}
+
+impl Ramp {
+    pub fn new(input: Arc<dyn ParallelTexture>, ramp: ColorRamp) -> Self {
+        Self { input, ramp }
+    }
+}
+
+impl Texture for Ramp {
+    fn value(&self, u: f64, v: f64, p: Vec3) -> Vec3 {
+        self.ramp.color(luminance(self.input.value(u, v, p)))
+    }
+
+    fn value_at(&self, hit_rec: &HitRecord) -> Vec3 {
+        self.ramp.color(luminance(self.input.value_at(hit_rec)))
+    }
+}
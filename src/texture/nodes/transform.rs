@@ -0,0 +1,172 @@
+use std::sync::Arc;
+
+use crate::{hitable::HitRecord, texture::ParallelTexture, types::Vec3, Texture};
+
+/// Moves the texture coordinates before looking up input.
+/// They are rotated around (0, 0) first, Then scaled & then offset
+#[derive(Clone)]
+pub struct UvTransform {
+    input: Arc<dyn ParallelTexture>,
+    scale: (f64, f64),
+    sin_theta: f64,
+    cos_theta: f64,
+    offset: (f64, f64),
+}
+
+impl UvTransform {
+    pub fn new(input: Arc<dyn ParallelTexture>) -> Self {
+        Self {
+            input,
+            scale: (1.0, 1.0),
+            sin_theta: 0.0,
+            cos_theta: 1.0,
+            offset: (0.0, 0.0),
+        }
+    }
+
+    pub fn with_scale(mut self, scale: (f64, f64)) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// angle is in degrees, Counter clockwise
+    pub fn with_rotation(mut self, angle: f64) -> Self {
+        let radians = angle.to_radians();
+        self.sin_theta = radians.sin();
+        self.cos_theta = radians.cos();
+        self
+    }
+
+    pub fn with_offset(mut self, offset: (f64, f64)) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    fn transform(&self, u: f64, v: f64) -> (f64, f64) {
+        let ru = self.cos_theta * u - self.sin_theta * v;
+        let rv = self.sin_theta * u + self.cos_theta * v;
+
+        (
+            ru * self.scale.0 + self.offset.0,
+            rv * self.scale.1 + self.offset.1,
+        )
+    }
+}
+
+impl Texture for UvTransform {
+    fn value(&self, u: f64, v: f64, p: Vec3) -> Vec3 {
+        let (u, v) = self.transform(u, v);
+
+        self.input.value(u, v, p)
+    }
+
+    fn value_at(&self, hit_rec: &HitRecord) -> Vec3 {
+        let mut hit_rec = hit_rec.clone();
+        (hit_rec.u, hit_rec.v) = self.transform(hit_rec.u, hit_rec.v);
+
+        // The tangents turn with the coordinates & the surface moves less per unit of the new
+        // coordinates when they are scaled up. Keeps the footprint of filtered lookups right
+        let (dpdu, dpdv) = (hit_rec.dpdu, hit_rec.dpdv);
+        hit_rec.dpdu =
+            (dpdu * self.cos_theta - dpdv * self.sin_theta) / self.scale.0.abs().max(f64::EPSILON);
+        hit_rec.dpdv =
+            (dpdu * self.sin_theta + dpdv * self.cos_theta) / self.scale.1.abs().max(f64::EPSILON);
+
+        self.input.value_at(&hit_rec)
+    }
+
+    fn alpha(&self, u: f64, v: f64, p: Vec3) -> f64 {
+        let (u, v) = self.transform(u, v);
+
+        self.input.alpha(u, v, p)
+    }
+
+    fn alpha_at(&self, hit_rec: &HitRecord) -> f64 {
+        let mut hit_rec = hit_rec.clone();
+        (hit_rec.u, hit_rec.v) = self.transform(hit_rec.u, hit_rec.v);
+
+        self.input.alpha_at(&hit_rec)
+    }
+}
+
+/// Moves the point in space before looking up input, For 3D textures like noise.
+/// It's rotated around the origin first, Then scaled & then offset
+#[derive(Clone)]
+pub struct PointTransform {
+    input: Arc<dyn ParallelTexture>,
+    scale: Vec3,
+    // Unit axis & angle of the rotation
+    axis: Vec3,
+    sin_theta: f64,
+    cos_theta: f64,
+    offset: Vec3,
+}
+
+impl PointTransform {
+    pub fn new(input: Arc<dyn ParallelTexture>) -> Self {
+        Self {
+            input,
+            scale: Vec3::splat(1.0),
+            axis: Vec3::new(0.0, 1.0, 0.0),
+            sin_theta: 0.0,
+            cos_theta: 1.0,
+            offset: Vec3::splat(0.0),
+        }
+    }
+
+    pub fn with_scale(mut self, scale: Vec3) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Rotates by angle degrees around axis
+    pub fn with_rotation(mut self, axis: Vec3, angle: f64) -> Self {
+        let radians = angle.to_radians();
+        self.axis = axis.unit_vector();
+        self.sin_theta = radians.sin();
+        self.cos_theta = radians.cos();
+        self
+    }
+
+    #[allow(dead_code)]
+    pub fn with_offset(mut self, offset: Vec3) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    fn transform(&self, p: Vec3) -> Vec3 {
+        // Rodrigues' rotation formula
+        let k = self.axis;
+        let rotated = p * self.cos_theta
+            + k.cross(&p) * self.sin_theta
+            + k * (k.dot(&p) * (1.0 - self.cos_theta));
+
+        rotated * self.scale + self.offset
+    }
+}
+
+impl Texture for PointTransform {
+    fn value(&self, u: f64, v: f64, p: Vec3) -> Vec3 {
+        self.input.value(u, v, self.transform(p))
+    }
+
+    fn value_at(&self, hit_rec: &HitRecord) -> Vec3 {
+        let mut hit_rec = hit_rec.clone();
+        hit_rec.p = self.transform(hit_rec.p);
+        hit_rec.local_p = self.transform(hit_rec.local_p);
+
+        self.input.value_at(&hit_rec)
+    }
+
+    fn alpha(&self, u: f64, v: f64, p: Vec3) -> f64 {
+        self.input.alpha(u, v, self.transform(p))
+    }
+
+    fn alpha_at(&self, hit_rec: &HitRecord) -> f64 {
+        let mut hit_rec = hit_rec.clone();
+        hit_rec.p = self.transform(hit_rec.p);
+        hit_rec.local_p = self.transform(hit_rec.local_p);
+
+        self.input.alpha_at(&hit_rec)
+    }
+}
@@ -0,0 +1,67 @@
+use std::sync::Arc;
+
+use crate::{hitable::HitRecord, texture::ParallelTexture, types::Vec3, Texture};
+
+/// Projects a 2D texture onto the surface along the X, Y & Z axes & blends the three
+/// by how much the normal faces each axis. Works on surfaces without texture coordinates &
+/// doesn't stretch anywhere, Unlike the uv mapping of a sphere near its poles
+#[derive(Clone)]
+pub struct Triplanar {
+    input: Arc<dyn ParallelTexture>,
+    // Texture repeats every 1 / scale units
+    scale: f64,
+    // Higher values make the transition between projections narrower
+    sharpness: f64,
+}
+
+impl Triplanar {
+    pub fn new(input: Arc<dyn ParallelTexture>) -> Self {
+        Self {
+            input,
+            scale: 1.0,
+            sharpness: 4.0,
+        }
+    }
+
+    pub fn with_scale(mut self, scale: f64) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    #[allow(dead_code)]
+    pub fn with_sharpness(mut self, sharpness: f64) -> Self {
+        self.sharpness = sharpness;
+        self
+    }
+}
+
+impl Texture for Triplanar {
+    /// Without a normal there's nothing to blend with, So this is just the projection along Y
+    fn value(&self, _u: f64, _v: f64, p: Vec3) -> Vec3 {
+        let p = p * self.scale;
+
+        self.input.value(p.x(), p.z(), p)
+    }
+
+    fn value_at(&self, hit_rec: &HitRecord) -> Vec3 {
+        let n = hit_rec.normal;
+        let weights = [n.x(), n.y(), n.z()].map(|w| w.abs().powf(self.sharpness));
+        let total: f64 = weights.iter().sum();
+
+        if total <= 0.0 {
+            return self.value(hit_rec.u, hit_rec.v, hit_rec.p);
+        }
+
+        let p = hit_rec.p * self.scale;
+
+        let projections = [(p.z(), p.y()), (p.x(), p.z()), (p.x(), p.y())];
+
+        projections
+            .iter()
+            .zip(weights.iter())
+            .filter(|(_, &w)| w > 0.0)
+            .fold(Vec3::splat(0.0), |acc, (&(u, v), &w)| {
+                acc + self.input.value(u, v, hit_rec.p) * (w / total)
+            })
+    }
+}
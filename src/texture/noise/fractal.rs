@@ -0,0 +1,146 @@
+use crate::{texture::noise::Noise, types::Vec3};
+
+/// Fractal brownian motion, Sums octaves of noise. Every octave has lacunarity times the
+/// frequency & gain times the amplitude of the previous one
+#[derive(Clone)]
+pub struct Fbm<N: Noise> {
+    noise: N,
+    octaves: u32,
+    lacunarity: f64,
+    gain: f64,
+}
+
+impl<N: Noise> Fbm<N> {
+    pub fn new(noise: N, octaves: u32) -> Self {
+        Self {
+            noise,
+            octaves,
+            lacunarity: 2.0,
+            gain: 0.5,
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn with_lacunarity(mut self, lacunarity: f64) -> Self {
+        self.lacunarity = lacunarity;
+        self
+    }
+
+    pub fn with_gain(mut self, gain: f64) -> Self {
+        self.gain = gain;
+        self
+    }
+}
+
+impl<N: Noise> Noise for Fbm<N> {
+    fn noise(&self, p: Vec3) -> f64 {
+        let (mut acc, mut total) = (0.0, 0.0);
+        let (mut amplitude, mut frequency) = (1.0, 1.0);
+
+        for _ in 0..self.octaves {
+            acc += amplitude * self.noise.noise(p * frequency);
+            total += amplitude;
+            amplitude *= self.gain;
+            frequency *= self.lacunarity;
+        }
+
+        // Keeps the result in the same range as a single octave
+        if total > 0.0 {
+            acc / total
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Ridged multifractal by Musgrave. Turns the zero crossings of the noise into sharp ridges
+/// & lets the octaves only add detail on top of the ridges. Good for mountains & veins
+#[derive(Clone)]
+pub struct Ridged<N: Noise> {
+    noise: N,
+    octaves: u32,
+    lacunarity: f64,
+    gain: f64,
+}
+
+impl<N: Noise> Ridged<N> {
+    pub fn new(noise: N, octaves: u32) -> Self {
+        Self {
+            noise,
+            octaves,
+            lacunarity: 2.0,
+            gain: 0.5,
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn with_lacunarity(mut self, lacunarity: f64) -> Self {
+        self.lacunarity = lacunarity;
+        self
+    }
+
+    #[allow(dead_code)]
+    pub fn with_gain(mut self, gain: f64) -> Self {
+        self.gain = gain;
+        self
+    }
+}
+
+impl<N: Noise> Noise for Ridged<N> {
+    fn noise(&self, p: Vec3) -> f64 {
+        let (mut acc, mut total) = (0.0, 0.0);
+        let (mut amplitude, mut frequency) = (1.0, 1.0);
+        let mut weight = 1.0;
+
+        for _ in 0..self.octaves {
+            let signal = 1.0 - self.noise.noise(p * frequency).abs();
+            let signal = signal * signal * weight;
+
+            // Octaves only show up where the previous ones were close to a ridge
+            weight = (signal * 2.0).clamp(0.0, 1.0);
+
+            acc += amplitude * signal;
+            total += amplitude;
+            amplitude *= self.gain;
+            frequency *= self.lacunarity;
+        }
+
+        if total > 0.0 {
+            acc / total * 2.0 - 1.0
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Looks up noise at a point pushed around by another noise, p + strength * warp(p).
+/// Gives swirly, Flowing patterns
+#[derive(Clone)]
+pub struct DomainWarp<N: Noise, W: Noise> {
+    noise: N,
+    warp: W,
+    strength: f64,
+}
+
+impl<N: Noise, W: Noise> DomainWarp<N, W> {
+    pub fn new(noise: N, warp: W, strength: f64) -> Self {
+        Self {
+            noise,
+            warp,
+            strength,
+        }
+    }
+}
+
+impl<N: Noise, W: Noise> Noise for DomainWarp<N, W> {
+    fn noise(&self, p: Vec3) -> f64 {
+        // Far apart offsets so that the 3 components aren't correlated
+        let offset = Vec3::new(
+            self.warp.noise(p),
+            self.warp.noise(p + Vec3::new(5.2, 1.3, 7.1)),
+            self.warp.noise(p + Vec3::new(1.7, 9.2, 3.4)),
+        );
+
+        self.noise.noise(p + offset * self.strength)
+    }
+}
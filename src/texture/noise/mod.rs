@@ -0,0 +1,31 @@
+mod fractal;
+mod perlin;
+mod simplex;
+mod worley;
+
+pub use fractal::{DomainWarp, Fbm, Ridged};
+pub use perlin::Perlin;
+pub use simplex::Simplex;
+pub use worley::Worley;
+
+use std::sync::Arc;
+
+use crate::types::Vec3;
+
+/// Smooth pseudo random function of a point in space, The building block of procedural textures.
+/// Values are roughly in [-1, 1] & features are about 1 unit apart
+pub trait Noise: Send + Sync {
+    fn noise(&self, p: Vec3) -> f64;
+}
+
+impl Noise for Perlin {
+    fn noise(&self, p: Vec3) -> f64 {
+        Perlin::noise(self, p)
+    }
+}
+
+impl<N: Noise + ?Sized> Noise for Arc<N> {
+    fn noise(&self, p: Vec3) -> f64 {
+        (**self).noise(p)
+    }
+}
@@ -0,0 +1,191 @@
+use crate::types::Vec3;
+use rand::{rngs::SmallRng, Rng, SeedableRng};
+
+const POINT_COUNT: usize = 256;
+
+/// Improved Perlin noise, See, https://mrl.cs.nyu.edu/~perlin/paper445.pdf
+///
+/// Noise only depends on the permutation table, So two generators made from the same seed
+/// give the exact same noise. It's immutable after creation & can be shared between
+/// textures with an `Arc`
+#[derive(Clone)]
+pub struct Perlin {
+    // Permutation of 0..256 repeated twice so that the nested lookups never have to wrap
+    permutation: Vec<usize>,
+}
+
+impl Perlin {
+    pub fn new<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        let mut p = (0..POINT_COUNT).collect::<Vec<usize>>();
+        permute(rng, &mut p);
+
+        let permutation = p.iter().chain(p.iter()).copied().collect();
+
+        Self { permutation }
+    }
+
+    /// Same seed, Same noise
+    pub fn with_seed(seed: u64) -> Self {
+        Self::new(&mut SmallRng::seed_from_u64(seed))
+    }
+
+    pub fn noise(&self, p: Vec3) -> f64 {
+        let (x, y, z) = (p.x(), p.y(), p.z());
+        let (i, j, k) = (cell(x), cell(y), cell(z));
+        let (x, y, z) = (x - x.floor(), y - y.floor(), z - z.floor());
+
+        let (u, v, w) = (fade(x), fade(y), fade(z));
+
+        let p = &self.permutation;
+        let a = p[i] + j;
+        let (aa, ab) = (p[a] + k, p[a + 1] + k);
+        let b = p[i + 1] + j;
+        let (ba, bb) = (p[b] + k, p[b + 1] + k);
+
+        lerp(
+            w,
+            lerp(
+                v,
+                lerp(u, grad3(p[aa], x, y, z), grad3(p[ba], x - 1.0, y, z)),
+                lerp(
+                    u,
+                    grad3(p[ab], x, y - 1.0, z),
+                    grad3(p[bb], x - 1.0, y - 1.0, z),
+                ),
+            ),
+            lerp(
+                v,
+                lerp(
+                    u,
+                    grad3(p[aa + 1], x, y, z - 1.0),
+                    grad3(p[ba + 1], x - 1.0, y, z - 1.0),
+                ),
+                lerp(
+                    u,
+                    grad3(p[ab + 1], x, y - 1.0, z - 1.0),
+                    grad3(p[bb + 1], x - 1.0, y - 1.0, z - 1.0),
+                ),
+            ),
+        )
+    }
+
+    /// Noise that also changes along a 4th dimension, Usually time. Moving along w
+    /// smoothly morphs the 3D noise instead of sliding it around
+    pub fn noise_4d(&self, p: Vec3, w: f64) -> f64 {
+        let coords = [p.x(), p.y(), p.z(), w];
+        let cells = coords.map(cell);
+        let f = coords.map(|c| c - c.floor());
+        let fades = f.map(fade);
+
+        let p = &self.permutation;
+
+        // Blends the 16 corners of the hypercube, One axis at a time
+        let mut values = [0.0; 16];
+        for (corner, value) in values.iter_mut().enumerate() {
+            let offset = [
+                corner & 1,
+                (corner >> 1) & 1,
+                (corner >> 2) & 1,
+                corner >> 3,
+            ];
+
+            let hash = (0..4).fold(0, |hash, axis| p[hash + cells[axis] + offset[axis]]);
+
+            *value = grad4(
+                hash,
+                f[0] - offset[0] as f64,
+                f[1] - offset[1] as f64,
+                f[2] - offset[2] as f64,
+                f[3] - offset[3] as f64,
+            );
+        }
+
+        let mut size = 16;
+        for t in fades {
+            size /= 2;
+            for i in 0..size {
+                values[i] = lerp(t, values[2 * i], values[2 * i + 1]);
+            }
+        }
+
+        values[0]
+    }
+
+    /// Sum of depth octaves of noise, Each one with double the frequency & half the
+    /// amplitude of the previous one
+    pub fn turbulence(&self, p: Vec3, depth: u32) -> f64 {
+        self.turbulence_with(p, depth, |p| self.noise(p))
+    }
+
+    /// `turbulence` over `noise_4d`, Only the spatial frequency doubles every octave
+    pub fn turbulence_4d(&self, p: Vec3, w: f64, depth: u32) -> f64 {
+        self.turbulence_with(p, depth, |p| self.noise_4d(p, w))
+    }
+
+    fn turbulence_with(&self, p: Vec3, depth: u32, noise: impl Fn(Vec3) -> f64) -> f64 {
+        let mut acc = 0.0f64;
+        let mut weight = 1.0;
+        let mut temp_p = p;
+
+        for _i in 0..depth {
+            acc += weight * noise(temp_p);
+            weight *= 0.5;
+            temp_p *= 2.0;
+        }
+
+        acc.abs()
+    }
+}
+
+fn cell(c: f64) -> usize {
+    (c.floor() as i64 & 255) as usize
+}
+
+// 6t^5 - 15t^4 + 10t^3, Unlike the cubic of the original noise its second derivative
+// is also 0 at the ends, So there are no visible seams along the grid in bump maps
+fn fade(t: f64) -> f64 {
+    t * t * t * (t * (t * 6.0 - 15.0) + 10.0)
+}
+
+fn lerp(t: f64, a: f64, b: f64) -> f64 {
+    a + t * (b - a)
+}
+
+// Dot product with one of the 12 directions to the edges of a cube, Picked by the hash
+fn grad3(hash: usize, x: f64, y: f64, z: f64) -> f64 {
+    let h = hash & 15;
+    let u = if h < 8 { x } else { y };
+    let v = if h < 4 {
+        y
+    } else if h == 12 || h == 14 {
+        x
+    } else {
+        z
+    };
+
+    (if h & 1 == 0 { u } else { -u }) + (if h & 2 == 0 { v } else { -v })
+}
+
+// Same for 4D with the 32 directions to the edges of a hypercube
+fn grad4(hash: usize, x: f64, y: f64, z: f64, w: f64) -> f64 {
+    let h = hash & 31;
+    let (a, b, c) = match h >> 3 {
+        0 => (y, z, w),
+        1 => (x, z, w),
+        2 => (x, y, w),
+        _ => (x, y, z),
+    };
+
+    (if h & 4 == 0 { -a } else { a })
+        + (if h & 2 == 0 { -b } else { b })
+        + (if h & 1 == 0 { -c } else { c })
+}
+
+fn permute<R: Rng + ?Sized>(rng: &mut R, p: &mut [usize]) {
+    let l = p.len();
+
+    for i in (0..l).rev() {
+        let r = rng.gen_range(0..=i);
+        p.swap(i, r);
+    }
+}
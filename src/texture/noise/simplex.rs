@@ -0,0 +1,111 @@
+use rand::Rng;
+
+use crate::{texture::noise::Noise, types::Vec3};
+
+// Skewing & unskewing factors for 3 dimensions
+const F3: f64 = 1.0 / 3.0;
+const G3: f64 = 1.0 / 6.0;
+
+// Directions to the middle of the edges of a cube
+const GRADIENTS: [[f64; 3]; 12] = [
+    [1.0, 1.0, 0.0],
+    [-1.0, 1.0, 0.0],
+    [1.0, -1.0, 0.0],
+    [-1.0, -1.0, 0.0],
+    [1.0, 0.0, 1.0],
+    [-1.0, 0.0, 1.0],
+    [1.0, 0.0, -1.0],
+    [-1.0, 0.0, -1.0],
+    [0.0, 1.0, 1.0],
+    [0.0, -1.0, 1.0],
+    [0.0, 1.0, -1.0],
+    [0.0, -1.0, -1.0],
+];
+
+/// Simplex noise, Sums the contributions of the 4 corners of the tetrahedron around a point
+/// instead of the 8 corners of a cube like `Perlin`. It's cheaper & doesn't have the grid
+/// aligned artifacts of Perlin noise.
+/// See, https://weber.itn.liu.se/~stegu/simplexnoise/simplexnoise.pdf
+#[derive(Clone)]
+pub struct Simplex {
+    // Permutation of 0..256 repeated twice so that lookups never have to wrap
+    permutation: Vec<usize>,
+}
+
+impl Simplex {
+    pub fn new<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        let mut p = (0..256).collect::<Vec<usize>>();
+        for i in (0..p.len()).rev() {
+            let r = rng.gen_range(0..=i);
+            p.swap(i, r);
+        }
+
+        let permutation = p.iter().chain(p.iter()).copied().collect();
+
+        Self { permutation }
+    }
+
+    fn gradient(&self, i: i64, j: i64, k: i64) -> [f64; 3] {
+        let p = &self.permutation;
+        let (i, j, k) = ((i & 255) as usize, (j & 255) as usize, (k & 255) as usize);
+
+        GRADIENTS[p[i + p[j + p[k]]] % 12]
+    }
+}
+
+impl Noise for Simplex {
+    fn noise(&self, p: Vec3) -> f64 {
+        // Find the cell of the skewed grid that p is in
+        let s = (p.x() + p.y() + p.z()) * F3;
+        let i = (p.x() + s).floor();
+        let j = (p.y() + s).floor();
+        let k = (p.z() + s).floor();
+
+        let t = (i + j + k) * G3;
+        let x0 = [p.x() - (i - t), p.y() - (j - t), p.z() - (k - t)];
+
+        // Which of the 6 tetrahedrons of the cell p is in, Found by ordering the coordinates
+        let (o1, o2) = if x0[0] >= x0[1] {
+            if x0[1] >= x0[2] {
+                ([1, 0, 0], [1, 1, 0])
+            } else if x0[0] >= x0[2] {
+                ([1, 0, 0], [1, 0, 1])
+            } else {
+                ([0, 0, 1], [1, 0, 1])
+            }
+        } else if x0[1] < x0[2] {
+            ([0, 0, 1], [0, 1, 1])
+        } else if x0[0] < x0[2] {
+            ([0, 1, 0], [0, 1, 1])
+        } else {
+            ([0, 1, 0], [1, 1, 0])
+        };
+
+        let (i, j, k) = (i as i64, j as i64, k as i64);
+
+        let corners = [[0, 0, 0], o1, o2, [1, 1, 1]];
+
+        corners
+            .iter()
+            .enumerate()
+            .map(|(n, offset)| {
+                let x = [
+                    x0[0] - offset[0] as f64 + n as f64 * G3,
+                    x0[1] - offset[1] as f64 + n as f64 * G3,
+                    x0[2] - offset[2] as f64 + n as f64 * G3,
+                ];
+
+                let falloff = 0.6 - x[0] * x[0] - x[1] * x[1] - x[2] * x[2];
+                if falloff < 0.0 {
+                    return 0.0;
+                }
+
+                let g = self.gradient(i + offset[0], j + offset[1], k + offset[2]);
+
+                falloff.powi(4) * (g[0] * x[0] + g[1] * x[1] + g[2] * x[2])
+            })
+            .sum::<f64>()
+            // Scales the result to [-1, 1]
+            * 32.0
+    }
+}
@@ -0,0 +1,94 @@
+use rand::Rng;
+
+use crate::{texture::noise::Noise, types::Vec3};
+
+/// What `Worley::cell` found around a point
+#[derive(Debug, Copy, Clone)]
+pub struct Cell {
+    /// Distance to the closest feature point
+    pub f1: f64,
+    /// Distance to the second closest feature point
+    pub f2: f64,
+    /// Random value in [0, 1) that's the same everywhere inside the cell of the closest point
+    pub id: f64,
+}
+
+/// Worley(cellular) noise. Space is split into unit cubes with one random feature point
+/// in each of them, & the noise is based on the distance to the closest feature points.
+/// Cells of the closest points form a Voronoi diagram
+#[derive(Clone)]
+pub struct Worley {
+    permutation: Vec<usize>,
+    // Position of the feature point inside of a cube & the id of its cell
+    points: Vec<(Vec3, f64)>,
+}
+
+impl Worley {
+    pub fn new<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        let mut permutation = (0..256).collect::<Vec<usize>>();
+        for i in (0..permutation.len()).rev() {
+            let r = rng.gen_range(0..=i);
+            permutation.swap(i, r);
+        }
+
+        let points = (0..256)
+            .map(|_| (Vec3::random(rng), rng.gen::<f64>()))
+            .collect();
+
+        Self {
+            permutation,
+            points,
+        }
+    }
+
+    pub fn cell(&self, p: Vec3) -> Cell {
+        let (i, j, k) = (
+            p.x().floor() as i64,
+            p.y().floor() as i64,
+            p.z().floor() as i64,
+        );
+
+        let mut cell = Cell {
+            f1: f64::MAX,
+            f2: f64::MAX,
+            id: 0.0,
+        };
+
+        // The closest points are always in one of the neighbouring cubes
+        for di in -1..=1 {
+            for dj in -1..=1 {
+                for dk in -1..=1 {
+                    let (ci, cj, ck) = (i + di, j + dj, k + dk);
+                    let (offset, id) = self.points[self.hash(ci, cj, ck)];
+
+                    let point = Vec3::new(ci as f64, cj as f64, ck as f64) + offset;
+                    let distance = (point - p).length();
+
+                    if distance < cell.f1 {
+                        cell.f2 = cell.f1;
+                        cell.f1 = distance;
+                        cell.id = id;
+                    } else if distance < cell.f2 {
+                        cell.f2 = distance;
+                    }
+                }
+            }
+        }
+
+        cell
+    }
+
+    fn hash(&self, i: i64, j: i64, k: i64) -> usize {
+        let p = &self.permutation;
+
+        p[(p[(p[(i & 255) as usize] + (j & 255) as usize) & 255] + (k & 255) as usize) & 255]
+    }
+}
+
+impl Noise for Worley {
+    /// Distance to the closest feature point moved from about [0, 1] to [-1, 1] like the other
+    /// noises, So it's -1 at the points themselves
+    fn noise(&self, p: Vec3) -> f64 {
+        self.cell(p).f1 * 2.0 - 1.0
+    }
+}
@@ -1,34 +1,81 @@
+use std::sync::Arc;
+
 use rand::Rng;
 
-use crate::{texture::Perlin, types::Vec3, Texture};
+use crate::{
+    hitable::HitRecord,
+    texture::{Perlin, Space},
+    types::Vec3,
+    Texture,
+};
 
 #[derive(Clone)]
 pub struct PerlinNoise {
-    noise: Perlin,
+    noise: Arc<Perlin>,
     scale: f64,
+    depth: u32,
+    speed: f64,
+    space: Space,
 }
 
 impl PerlinNoise {
     #[allow(dead_code)]
     pub fn new<R: Rng + ?Sized>(rng: &mut R) -> Self {
-        Self {
-            noise: Perlin::new(rng),
-            scale: 1.0,
-        }
+        Self::with_scale(rng, 1.0)
     }
 
     pub fn with_scale<R: Rng + ?Sized>(rng: &mut R, scale: f64) -> Self {
+        Self::from_perlin(Arc::new(Perlin::new(rng)), scale)
+    }
+
+    /// Uses an existing generator, Textures made from the same one show the same pattern
+    pub fn from_perlin(noise: Arc<Perlin>, scale: f64) -> Self {
         Self {
-            noise: Perlin::new(rng),
+            noise,
             scale,
+            depth: 7,
+            speed: 0.0,
+            space: Space::World,
         }
     }
+
+    /// Number of octaves of turbulence, Fewer gives a smoother pattern. Defaults to 7
+    pub fn with_depth(mut self, depth: u32) -> Self {
+        self.depth = depth;
+        self
+    }
+
+    /// Makes the pattern churn over time, speed is how far along the 4th dimension of the
+    /// noise it moves per unit of ray time. 0.0, The default, Keeps it still
+    pub fn with_speed(mut self, speed: f64) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    /// Noise sticks to the object instead of the object moving through it
+    #[allow(dead_code)]
+    pub fn in_object_space(mut self) -> Self {
+        self.space = Space::Object;
+        self
+    }
+
+    fn pattern(&self, p: Vec3, time: f64) -> Vec3 {
+        let turbulence = if self.speed == 0.0 {
+            self.noise.turbulence(p, self.depth)
+        } else {
+            self.noise.turbulence_4d(p, time * self.speed, self.depth)
+        };
+
+        Vec3::new(1.0, 1.0, 1.0) * 0.5 * (1.0 + (self.scale * p.z() + 10.0 * turbulence).sin())
+    }
 }
 
 impl Texture for PerlinNoise {
     fn value(&self, _u: f64, _v: f64, p: Vec3) -> Vec3 {
-        Vec3::new(1.0, 1.0, 1.0)
-            * 0.5
-            * (1.0 + (self.scale * p.z() + 10.0 * self.noise.turbulence(p, 7)).sin())
+        self.pattern(p, 0.0)
+    }
+
+    fn value_at(&self, hit_rec: &HitRecord) -> Vec3 {
+        self.pattern(self.space.point(hit_rec), hit_rec.time)
     }
 }
@@ -0,0 +1,68 @@
+use crate::{
+    hitable::HitRecord,
+    texture::{noise::Noise, ColorRamp, Space},
+    types::Vec3,
+    Texture,
+};
+
+/// Growth rings around the Y axis. Noise makes the rings wobble & the ramp is
+/// looked up with the position inside of a ring, 0 at its inner edge and 1 at the outer one
+#[derive(Clone)]
+pub struct Wood<N: Noise> {
+    noise: N,
+    ramp: ColorRamp,
+    // Number of rings per unit
+    rings: f64,
+    distortion: f64,
+    scale: f64,
+    space: Space,
+}
+
+impl<N: Noise> Wood<N> {
+    pub fn new(noise: N, ramp: ColorRamp) -> Self {
+        Self {
+            noise,
+            ramp,
+            rings: 4.0,
+            distortion: 0.3,
+            scale: 1.0,
+            space: Space::World,
+        }
+    }
+
+    pub fn with_rings(mut self, rings: f64) -> Self {
+        self.rings = rings;
+        self
+    }
+
+    pub fn with_distortion(mut self, distortion: f64) -> Self {
+        self.distortion = distortion;
+        self
+    }
+
+    #[allow(dead_code)]
+    pub fn with_scale(mut self, scale: f64) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Pattern sticks to the object instead of the object moving through it
+    #[allow(dead_code)]
+    pub fn in_object_space(mut self) -> Self {
+        self.space = Space::Object;
+        self
+    }
+}
+
+impl<N: Noise> Texture for Wood<N> {
+    fn value(&self, _u: f64, _v: f64, p: Vec3) -> Vec3 {
+        let radius = (p.x() * p.x() + p.z() * p.z()).sqrt();
+        let rings = radius * self.rings + self.distortion * self.noise.noise(p * self.scale);
+
+        self.ramp.color(rings - rings.floor())
+    }
+
+    fn value_at(&self, hit_rec: &HitRecord) -> Vec3 {
+        self.value(hit_rec.u, hit_rec.v, self.space.point(hit_rec))
+    }
+}
@@ -1,9 +1,13 @@
 mod color;
 mod dimension;
+mod matrix;
+mod quaternion;
 mod ray;
 
 pub use color::Color;
 pub use dimension::{Dimension, X, Y, Z};
+pub use matrix::Mat4;
+pub use quaternion::Quaternion;
 pub use ray::Ray;
 
 #[cfg(not(target_arch = "x86_64"))]
@@ -0,0 +1,103 @@
+use crate::types::{Mat4, Vec3};
+
+/// Unit quaternion representing a rotation, w is the real part & v the imaginary one
+#[derive(Debug, Copy, Clone)]
+pub struct Quaternion {
+    pub w: f64,
+    pub v: Vec3,
+}
+
+impl Quaternion {
+    pub fn identity() -> Self {
+        Self {
+            w: 1.0,
+            v: Vec3::splat(0.0),
+        }
+    }
+
+    /// Counter clockwise rotation by angle(in degrees) about axis, Same as `Mat4::rotation`
+    pub fn from_axis_angle(axis: Vec3, angle: f64) -> Self {
+        let (sin, cos) = (angle.to_radians() / 2.0).sin_cos();
+
+        Self {
+            w: cos,
+            v: axis.unit_vector() * sin,
+        }
+    }
+
+    pub fn dot(&self, other: &Self) -> f64 {
+        self.w * other.w + self.v.dot(&other.v)
+    }
+
+    /// Angle(in radians) of the rotation that takes self to other
+    pub fn angle_to(&self, other: &Self) -> f64 {
+        2.0 * self.dot(other).abs().min(1.0).acos()
+    }
+
+    /// Spherical linear interpolation, Rotates at a constant speed along the shortest arc
+    pub fn slerp(self, other: Self, t: f64) -> Self {
+        let mut cos = self.dot(&other);
+
+        // q & -q are the same rotation, Going to the closer one takes the short way around
+        let other = if cos < 0.0 {
+            cos = -cos;
+            Self {
+                w: -other.w,
+                v: -other.v,
+            }
+        } else {
+            other
+        };
+
+        // Nearly the same rotation, sin of the angle is too small to divide by
+        let (a, b) = if cos > 0.9995 {
+            (1.0 - t, t)
+        } else {
+            let theta = cos.acos();
+            let sin = theta.sin();
+            (((1.0 - t) * theta).sin() / sin, (t * theta).sin() / sin)
+        };
+
+        Self {
+            w: a * self.w + b * other.w,
+            v: self.v * a + other.v * b,
+        }
+        .normalize()
+    }
+
+    pub fn normalize(self) -> Self {
+        let len = self.dot(&self).sqrt();
+
+        Self {
+            w: self.w / len,
+            v: self.v / len,
+        }
+    }
+
+    pub fn to_matrix(self) -> Mat4 {
+        let w = self.w;
+        let (x, y, z) = (self.v.x(), self.v.y(), self.v.z());
+
+        Mat4::new([
+            [
+                1.0 - 2.0 * (y * y + z * z),
+                2.0 * (x * y - w * z),
+                2.0 * (x * z + w * y),
+                0.0,
+            ],
+            [
+                2.0 * (x * y + w * z),
+                1.0 - 2.0 * (x * x + z * z),
+                2.0 * (y * z - w * x),
+                0.0,
+            ],
+            [
+                2.0 * (x * z - w * y),
+                2.0 * (y * z + w * x),
+                1.0 - 2.0 * (x * x + y * y),
+                0.0,
+            ],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+}
@@ -1,11 +1,21 @@
-use rand::prelude::SmallRng;
+use std::sync::Arc;
 
-use crate::{hitable::Hitable, types::Vec3};
+use rand::{prelude::SmallRng, Rng};
+
+use crate::{
+    hitable::{shapes::ParallelSample, HitRecord, Hitable},
+    types::Vec3,
+    Background,
+};
 
 pub struct Ray {
     pub origin: Vec3,
     pub direction: Vec3,
     time: f64,
+    /// Angle(in radians) by which the cone around the ray widens per unit distance
+    /// Used to estimate how much of a texture is covered by a single pixel.
+    /// 0.0 when unknown, Which is the case for all the scattered rays
+    spread: f64,
 }
 
 impl Ray {
@@ -14,9 +24,15 @@ impl Ray {
             origin,
             direction,
             time,
+            spread: 0.0,
         }
     }
 
+    pub fn with_spread(mut self, spread: f64) -> Ray {
+        self.spread = spread;
+        self
+    }
+
     #[inline]
     pub fn point_at_parameter(&self, t: f64) -> Vec3 {
         self.origin + self.direction * t
@@ -25,30 +41,121 @@ impl Ray {
     pub const fn time(&self) -> f64 {
         self.time
     }
+    #[inline]
+    pub const fn spread(&self) -> f64 {
+        self.spread
+    }
 
     pub fn color<T: Hitable>(
         &self,
         world: &T,
         rng: &mut SmallRng,
-        background: &Vec3,
+        background: &dyn Background,
+        lights: &[Arc<dyn ParallelSample>],
         depth: u32,
     ) -> Vec3 {
-        if let Some(hit_rec) = world.hit(self, 0.001, std::f64::MAX) {
+        if let Some(mut hit_rec) = world.hit(self, 0.001, std::f64::MAX) {
+            hit_rec.footprint = self.spread * hit_rec.t * self.direction.length();
+            hit_rec.time = self.time;
+
             if depth >= 50 {
                 Vec3::splat(0.0f64)
             } else {
                 let material = hit_rec.material;
-                let emitted_color = hit_rec.material.emit(hit_rec.u, hit_rec.v, hit_rec.p);
+                let emitted_color = hit_rec.material.emit(self, &hit_rec);
 
                 if let (attenuation, Some(scattered_ray)) = material.scatter(self, &hit_rec, rng) {
+                    let (scattered_ray, weight) =
+                        self.sample_lights(scattered_ray, &hit_rec, background, lights, rng);
+
+                    if weight == 0.0 {
+                        return emitted_color;
+                    }
+
                     emitted_color
-                        + attenuation * scattered_ray.color(world, rng, background, depth + 1)
+                        + attenuation
+                            * weight
+                            * scattered_ray.color(world, rng, background, lights, depth + 1)
                 } else {
                     emitted_color
                 }
             }
         } else {
-            *background
+            background.value(self)
+        }
+    }
+
+    /// Sometimes replaces the ray picked by the material with one pointed towards the bright
+    /// parts of the background or towards one of the area lights. The material & each of the
+    /// other strategies are picked equally often and the ray is weighted by how likely all of
+    /// them were to pick it, So that small & bright lights like the sun don't show up as fireflies.
+    ///
+    /// Materials that can't tell how likely a direction is(mirrors, glass, ...) are left alone,
+    /// Backgrounds that can't be sampled are skipped
+    fn sample_lights(
+        &self,
+        scattered_ray: Ray,
+        hit_rec: &HitRecord,
+        background: &dyn Background,
+        lights: &[Arc<dyn ParallelSample>],
+        rng: &mut SmallRng,
+    ) -> (Ray, f64) {
+        let material = hit_rec.material;
+
+        if material
+            .scattering_pdf(self, hit_rec, &scattered_ray)
+            .is_none()
+        {
+            return (scattered_ray, 1.0);
+        }
+
+        let use_background = background.pdf(&scattered_ray.direction).is_some();
+        let use_lights = !lights.is_empty();
+        let strategies = 1 + use_background as usize + use_lights as usize;
+        if strategies == 1 {
+            return (scattered_ray, 1.0);
+        }
+
+        let (origin, time) = (scattered_ray.origin, scattered_ray.time);
+        let direction = match rng.gen_range(0..strategies) {
+            0 => None,
+            1 if use_background => background.sample_direction(rng),
+            _ => {
+                let light = &lights[rng.gen_range(0..lights.len())];
+                let (point, _) = light.sample(rng);
+
+                Some(point - origin)
+            }
+        };
+        let scattered_ray = match direction {
+            Some(direction) => Ray::new(origin, direction, time),
+            None => scattered_ray,
+        };
+
+        let material_pdf = material
+            .scattering_pdf(self, hit_rec, &scattered_ray)
+            .unwrap_or(0.0);
+        let background_pdf = if use_background {
+            background.pdf(&scattered_ray.direction).unwrap_or(0.0)
+        } else {
+            0.0
+        };
+        let light_pdf = if use_lights {
+            lights
+                .iter()
+                .map(|light| light.pdf(origin, scattered_ray.direction))
+                .sum::<f64>()
+                / lights.len() as f64
+        } else {
+            0.0
+        };
+
+        let pdf = (material_pdf + background_pdf + light_pdf) / strategies as f64;
+
+        if pdf <= 0.0 {
+            return (scattered_ray, 0.0);
         }
+
+        (scattered_ray, material_pdf / pdf)
     }
 }